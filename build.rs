@@ -0,0 +1,69 @@
+/*
+ * This file contains template code.
+ * With the `embed-inputs` feature, bakes `src/<year>/inputs/NN.txt` into the binaries.
+ */
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_none() {
+        return;
+    }
+
+    let src = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    // also picks up inputs and years that are added later.
+    println!("cargo:rerun-if-changed={}", src.display());
+
+    let mut inputs = vec![];
+    for (year, dir) in year_dirs(&src) {
+        for (day, path) in input_files(&dir.join("inputs")) {
+            inputs.push((year, day, path));
+        }
+    }
+    inputs.sort();
+
+    let entries = inputs
+        .iter()
+        .map(|(year, day, path)| {
+            format!(
+                "    ({}, {}, include_str!({:?})),\n",
+                year,
+                day,
+                path.display().to_string()
+            )
+        })
+        .collect::<String>();
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("embedded_inputs.rs");
+    let code = format!(
+        "pub static INPUTS: &[(u16, u8, &str)] = &[\n{}];\n",
+        entries
+    );
+    fs::write(out, code).expect("could not write embedded inputs.");
+}
+
+fn year_dirs(src: &Path) -> Vec<(u16, PathBuf)> {
+    read_dir(src)
+        .into_iter()
+        .filter_map(|path| Some((path.file_name()?.to_str()?.parse().ok()?, path)))
+        .filter(|(_, path)| path.is_dir())
+        .collect()
+}
+
+/// Only `NN.txt`, not the `NN_MM.txt` files of `read_file_extended`.
+fn input_files(dir: &Path) -> Vec<(u8, PathBuf)> {
+    read_dir(dir)
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|e| e == "txt"))
+        .filter_map(|path| Some((path.file_stem()?.to_str()?.parse().ok()?, path)))
+        .collect()
+}
+
+fn read_dir(dir: &Path) -> Vec<PathBuf> {
+    match fs::read_dir(dir) {
+        Ok(entries) => entries.filter_map(|e| e.ok().map(|e| e.path())).collect(),
+        Err(_) => vec![],
+    }
+}
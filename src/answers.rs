@@ -0,0 +1,266 @@
+/*
+ * This file contains template code.
+ * Accepted answers for the real inputs, stored in `src/<year>/answers/NN.txt`:
+ * the first line holds the answer to part one, the second line the answer to part two.
+ * Leave a line empty if the answer is not known yet.
+ * Expected answers for numbered examples live next to them, see `read_example_answers`.
+ */
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::{ANSI_BOLD, ANSI_RESET};
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(contents: &str) -> Self {
+        let mut lines = contents
+            .lines()
+            .map(|l| Some(l.trim()).filter(|l| !l.is_empty()).map(String::from));
+        Answers {
+            part_one: lines.next().flatten(),
+            part_two: lines.next().flatten(),
+        }
+    }
+
+    /// Reads `src/<year>/answers/NN.txt`, returns `None` if there is no answers file for the day.
+    pub fn read(year: u16, day: u8) -> Option<Self> {
+        fs::read_to_string(crate::input_path("answers", year, day))
+            .ok()
+            .map(|contents| Self::parse(&contents))
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn verify(&self, part: u8, actual: Option<&str>) -> Verdict {
+        match (self.get(part), actual) {
+            (None, _) => Verdict::MissingAnswer,
+            (Some(_), None) => Verdict::Unsolved,
+            (Some(expected), Some(actual)) if expected == actual => Verdict::Match,
+            (Some(expected), Some(actual)) => Verdict::Mismatch {
+                expected: expected.to_string(),
+                actual: actual.to_string(),
+            },
+        }
+    }
+}
+
+/// Sidecar of the examples `src/<year>/examples/NN_MM.txt`.
+pub fn example_answers_path(year: u16, day: u8) -> PathBuf {
+    crate::year_dir("examples", year).join(format!("{:02}_answers.toml", day))
+}
+
+/// Parses the expected answers of numbered examples, keyed by example number:
+///
+/// ```toml
+/// [01]
+/// part_one = 157
+/// part_two = "CMZ"
+/// ```
+pub fn parse_example_answers(contents: &str) -> Result<BTreeMap<u8, Answers>, String> {
+    let table = contents.parse::<toml::Table>().map_err(|e| e.to_string())?;
+
+    let mut examples = BTreeMap::new();
+    for (example, parts) in table {
+        let number = example
+            .parse::<u8>()
+            .map_err(|_| format!("invalid example \"{}\"", example))?;
+        let parts = parts
+            .as_table()
+            .ok_or_else(|| format!("example \"{}\" is not a table", example))?;
+
+        let mut answers = Answers::default();
+        for (part, answer) in parts {
+            let answer = match answer {
+                toml::Value::String(s) => s.clone(),
+                toml::Value::Integer(i) => i.to_string(),
+                _ => return Err(format!("invalid answer for {}.{}", example, part)),
+            };
+            match part.as_str() {
+                "part_one" => answers.part_one = Some(answer),
+                "part_two" => answers.part_two = Some(answer),
+                _ => {
+                    return Err(format!(
+                        "unknown part \"{}\" in example \"{}\"",
+                        part, example
+                    ))
+                }
+            }
+        }
+        examples.insert(number, answers);
+    }
+    Ok(examples)
+}
+
+pub fn read_example_answers(year: u16, day: u8) -> Result<BTreeMap<u8, Answers>, String> {
+    let path = example_answers_path(year, day);
+    fs::read_to_string(&path)
+        .map_err(|e| format!("could not read \"{}\": {}", path.display(), e))
+        .and_then(|contents| parse_example_answers(&contents))
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Runs both parts on every example listed in the sidecar and panics if any of them does not
+/// produce the expected answer. Parts without an expected answer are skipped.
+pub fn check_examples<A: Display, B: Display>(
+    year: u16,
+    day: u8,
+    part_one: impl Fn(&str) -> Option<A>,
+    part_two: impl Fn(&str) -> Option<B>,
+) {
+    let examples = read_example_answers(year, day).unwrap_or_else(|e| panic!("{}", e));
+    let failures = example_failures(
+        &examples,
+        |example| crate::read_file_extended("examples", year, day, example),
+        part_one,
+        part_two,
+    );
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Describes every part that does not produce its expected answer. Examples without any
+/// expected answer are not even read, so stubs of `cargo scaffold` do not fail.
+pub fn example_failures<A: Display, B: Display>(
+    examples: &BTreeMap<u8, Answers>,
+    read_example: impl Fn(u8) -> String,
+    part_one: impl Fn(&str) -> Option<A>,
+    part_two: impl Fn(&str) -> Option<B>,
+) -> Vec<String> {
+    let mut failures = vec![];
+    for (example, answers) in examples {
+        if answers.part_one.is_none() && answers.part_two.is_none() {
+            continue;
+        }
+        let input = read_example(*example);
+        let results = [
+            (1, part_one(&input).map(|a| a.to_string())),
+            (2, part_two(&input).map(|a| a.to_string())),
+        ];
+        for (part, actual) in results {
+            let failure = match answers.verify(part, actual.as_deref()) {
+                Verdict::Mismatch { expected, actual } => {
+                    format!("expected {}, got {}", expected, actual)
+                }
+                Verdict::Unsolved => "not solved".to_string(),
+                _ => continue,
+            };
+            failures.push(format!("example {:02} part {}: {}", example, part, failure));
+        }
+    }
+    failures
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// The solver returned `None`.
+    Unsolved,
+    /// There is no accepted answer for this part yet.
+    MissingAnswer,
+    /// The input could not be loaded, with the reason.
+    InputError(String),
+}
+
+impl Verdict {
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Mismatch { .. } | Verdict::Unsolved)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Match => write!(f, "✅ match"),
+            Verdict::Mismatch { expected, actual } => write!(
+                f,
+                "❌ {}mismatch{}: expected {}, got {}",
+                ANSI_BOLD, ANSI_RESET, expected, actual
+            ),
+            Verdict::Unsolved => write!(f, "❌ {}not solved{}", ANSI_BOLD, ANSI_RESET),
+            Verdict::MissingAnswer => write!(f, "❔ no accepted answer recorded"),
+            Verdict::InputError(e) => write!(f, "❔ {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        let answers = Answers::parse("24000\n45000\n");
+        assert_eq!(answers.get(1), Some("24000"));
+        assert_eq!(answers.get(2), Some("45000"));
+
+        let answers = Answers::parse("CMZ\n");
+        assert_eq!(answers.get(1), Some("CMZ"));
+        assert_eq!(answers.get(2), None);
+
+        let answers = Answers::parse("\n 42 \n");
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.get(2), Some("42"));
+    }
+
+    #[test]
+    fn test_parse_example_answers() {
+        let examples =
+            parse_example_answers("[01]\npart_one = 31\n\n[02]\npart_two = \"CMZ\"\n").unwrap();
+        assert_eq!(examples[&1].get(1), Some("31"));
+        assert_eq!(examples[&1].get(2), None);
+        assert_eq!(examples[&2].get(2), Some("CMZ"));
+
+        assert!(parse_example_answers("[a]\npart_one = 1").is_err());
+        assert!(parse_example_answers("[01]\npart_three = 1").is_err());
+        assert!(parse_example_answers("[01]\npart_one = 1.5").is_err());
+    }
+
+    #[test]
+    fn test_example_failures() {
+        let examples = parse_example_answers("[01]\npart_one = 3\n\n[02]\n").unwrap();
+        let failures = example_failures(
+            &examples,
+            |example| {
+                assert_eq!(example, 1, "examples without answers are not read");
+                "1\n2\n".to_string()
+            },
+            |input| Some(input.lines().count()),
+            |_| None::<u32>,
+        );
+        assert_eq!(failures, vec!["example 01 part 1: expected 3, got 2"]);
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("1\n2");
+        assert_eq!(answers.verify(1, Some("1")), Verdict::Match);
+        assert_eq!(
+            answers.verify(2, Some("3")),
+            Verdict::Mismatch {
+                expected: "2".to_string(),
+                actual: "3".to_string()
+            }
+        );
+        assert_eq!(answers.verify(2, None), Verdict::Unsolved);
+        assert_eq!(
+            Answers::default().verify(1, Some("1")),
+            Verdict::MissingAnswer
+        );
+    }
+}
@@ -0,0 +1,195 @@
+/*
+ * This file contains template code.
+ * Saved timings of a previous run of the all-days runner, used to spot performance regressions.
+ */
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::output::{Record, Status};
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// Where the baseline of a year is stored unless `--baseline` is given.
+pub fn default_path(year: u16) -> PathBuf {
+    crate::project_dir()
+        .join("target")
+        .join(format!("aoc-bench-{}.json", year))
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    /// Whether the timings are medians of `--bench` runs instead of single runs. Baselines from
+    /// before this was recorded are single runs.
+    #[serde(default)]
+    pub benchmarked: bool,
+    /// day -> part -> duration in nanoseconds.
+    pub timings: BTreeMap<u8, BTreeMap<u8, u64>>,
+}
+
+impl Baseline {
+    /// Collects the timings of all solved parts.
+    pub fn from_records(records: &[Record], benchmarked: bool) -> Self {
+        let mut baseline = Baseline {
+            benchmarked,
+            ..Baseline::default()
+        };
+        for record in records.iter().filter(|r| r.status == Status::Solved) {
+            baseline
+                .timings
+                .entry(record.day)
+                .or_default()
+                .insert(record.part, record.elapsed.as_nanos() as u64);
+        }
+        baseline
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self).unwrap())
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<Duration> {
+        self.timings
+            .get(&day)
+            .and_then(|parts| parts.get(&part))
+            .map(|ns| Duration::from_nanos(*ns))
+    }
+
+    /// Medians of `--bench` runs and single cold runs differ too much to compare them.
+    pub fn check_mode(&self, benchmarked: bool) -> Result<(), String> {
+        let mode = |benchmarked| match benchmarked {
+            true => "with --bench",
+            false => "without --bench",
+        };
+        match self.benchmarked == benchmarked {
+            true => Ok(()),
+            false => Err(format!(
+                "the baseline was saved {}, compare against it {} as well",
+                mode(self.benchmarked),
+                mode(self.benchmarked)
+            )),
+        }
+    }
+
+    /// Compares all solved parts in `records` that also have a timing in the baseline.
+    pub fn compare(&self, records: &[Record]) -> Vec<Comparison> {
+        records
+            .iter()
+            .filter(|r| r.status == Status::Solved)
+            .filter_map(|r| {
+                self.get(r.day, r.part).map(|baseline| Comparison {
+                    day: r.day,
+                    part: r.part,
+                    baseline,
+                    current: r.elapsed,
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    /// Relative change against the baseline in percent, positive means slower.
+    pub fn change(&self) -> f64 {
+        if self.baseline.is_zero() {
+            return 0_f64;
+        }
+        (self.current.as_secs_f64() / self.baseline.as_secs_f64() - 1_f64) * 100_f64
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Day {:02} Part {}: {:.2?} -> {:.2?} {}({:+.1}%){}",
+            self.day,
+            self.part,
+            self.baseline,
+            self.current,
+            ANSI_ITALIC,
+            self.change(),
+            ANSI_RESET
+        )
+    }
+}
+
+/// Prints all comparisons and returns whether any part regressed past `threshold` percent.
+pub fn print_comparisons(comparisons: &[Comparison], threshold: f64) -> bool {
+    let mut regressed = false;
+    for comparison in comparisons {
+        if comparison.is_regression(threshold) {
+            regressed = true;
+            eprintln!("{} {}REGRESSION{}", comparison, ANSI_BOLD, ANSI_RESET);
+        } else {
+            eprintln!("{}", comparison);
+        }
+    }
+    regressed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_with_baseline() {
+        let baseline = Baseline::from_records(
+            &[
+                Record::new(1, 1, Some(1), Duration::from_millis(10)),
+                Record::new(1, 2, Some(2), Duration::from_millis(10)),
+                Record::new(2, 1, None::<u32>, Duration::from_millis(10)),
+            ],
+            false,
+        );
+        assert_eq!(baseline.get(1, 1), Some(Duration::from_millis(10)));
+        assert_eq!(baseline.get(2, 1), None);
+
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+
+        let comparisons = baseline.compare(&[
+            Record::new(1, 1, Some(1), Duration::from_millis(12)),
+            Record::new(1, 2, Some(2), Duration::from_millis(5)),
+            Record::new(2, 1, Some(3), Duration::from_millis(5)),
+        ]);
+        assert_eq!(comparisons.len(), 2);
+        assert!((comparisons[0].change() - 20_f64).abs() < 1.0e-6);
+        assert!((comparisons[1].change() + 50_f64).abs() < 1.0e-6);
+        assert!(comparisons[0].is_regression(10_f64));
+        assert!(!comparisons[0].is_regression(25_f64));
+        assert!(!comparisons[1].is_regression(10_f64));
+
+        assert!(baseline.check_mode(false).is_ok());
+        assert_eq!(
+            baseline.check_mode(true),
+            Err("the baseline was saved without --bench, compare against it without --bench as well".to_string())
+        );
+        let old = serde_json::from_str::<Baseline>("{\"timings\":{}}").unwrap();
+        assert!(!old.benchmarked);
+    }
+}
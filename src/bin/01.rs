@@ -16,8 +16,9 @@ pub fn part_two(input: &str) -> Option<u32> {
     Some(it.map(|ng| ng.sum::<u32>()).sorted().rev().take(3).sum())
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 1);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 1);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -28,13 +29,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file("examples", 2022, 1);
         assert_eq!(part_one(&input), Some(24000));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 1);
+        let input = advent_of_code::read_file("examples", 2022, 1);
         assert_eq!(part_two(&input), Some(45000));
     }
 }
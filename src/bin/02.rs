@@ -24,7 +24,7 @@ impl TryFrom<&str> for RPSAction {
             "B" | "Y" => Ok(RPSAction::Paper),
             "C" | "Z" => Ok(RPSAction::Scissors),
             _ => {
-                println!("Error unknown string \"{:?}\"", value);
+                eprintln!("Error unknown string \"{:?}\"", value);
                 Err(())
             }
         }
@@ -46,7 +46,7 @@ impl TryFrom<&str> for RPSResult {
             "Y" => Ok(RPSResult::Draw),
             "Z" => Ok(RPSResult::Win),
             _ => {
-                println!("Error unknown string \"{:?}\"", value);
+                eprintln!("Error unknown string \"{:?}\"", value);
                 Err(())
             }
         }
@@ -119,8 +119,9 @@ pub fn part_two(input: &str) -> Option<i32> {
     Some(it.map(|a| a.0.score_for_result(&a.1)).sum())
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 2);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -131,13 +132,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file("examples", 2022, 2);
         assert_eq!(part_one(&input), Some(15));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
+        let input = advent_of_code::read_file("examples", 2022, 2);
         assert_eq!(part_two(&input), Some(12));
     }
 }
@@ -14,7 +14,7 @@ fn gather_same_items(left: &[char], right: &[char]) -> Vec<char> {
 }
 
 fn gather_same_items_in_rucksack_group(rucksack_group: &[&str]) -> Vec<char> {
-    let compare_rucksack = rucksack_group.first().unwrap().chars().into_iter().unique();
+    let compare_rucksack = rucksack_group.first().unwrap().chars().unique();
     let mut same_items = compare_rucksack.collect_vec();
 
     for rucksack in rucksack_group.iter().skip(1) {
@@ -57,8 +57,9 @@ pub fn part_two(input: &str) -> Option<u32> {
     )
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 3);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -69,13 +70,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2022, 3);
         assert_eq!(part_one(&input), Some(157));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
+        let input = advent_of_code::read_file("examples", 2022, 3);
         assert_eq!(part_two(&input), Some(70));
     }
 }
@@ -44,8 +44,9 @@ pub fn part_two(input: &str) -> Option<u32> {
     )
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 4);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -56,13 +57,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 4);
+        let input = advent_of_code::read_file("examples", 2022, 4);
         assert_eq!(part_one(&input), Some(2));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 4);
+        let input = advent_of_code::read_file("examples", 2022, 4);
         assert_eq!(part_two(&input), Some(4));
     }
 }
@@ -130,8 +130,9 @@ pub fn part_two(input: &str) -> Option<String> {
     Some(ship.top_of_stacks())
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 5);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -142,13 +143,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = advent_of_code::read_file("examples", 2022, 5);
         assert_eq!(part_one(&input), Some(String::from("CMZ")));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
+        let input = advent_of_code::read_file("examples", 2022, 5);
         assert_eq!(part_two(&input), Some(String::from("MCD")));
     }
 }
@@ -30,8 +30,9 @@ pub fn part_two(input: &str) -> Option<u32> {
     None
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 6);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 6);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -42,13 +43,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 6);
+        let input = advent_of_code::read_file("examples", 2022, 6);
         assert_eq!(part_one(&input), Some(7));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 6);
+        let input = advent_of_code::read_file("examples", 2022, 6);
         assert_eq!(part_two(&input), Some(19));
     }
 }
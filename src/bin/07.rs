@@ -162,15 +162,15 @@ pub fn part_one(_input: &str) -> Option<u32> {
 pub fn part_two(_input: &str) -> Option<u32> {
     let dir = setup_directory_from_commands(_input);
     let size_to_free = dir.borrow().get_size() - 40000000;
-    println!("Find: {:?}", size_to_free);
     let result = dir
         .borrow()
         .get_size_of_smallest_dir_with_size_atleast(size_to_free);
     result
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 7);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -181,13 +181,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_one(&input), Some(95437));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file("examples", 2022, 7);
         assert_eq!(part_two(&input), Some(24933642));
     }
 }
@@ -72,14 +72,14 @@ pub fn part_two(_input: &str) -> Option<u32> {
         _input.lines().count() as i32,
     );
 
-    let directions = vec![(0, -1), (1, 0), (0, 1), (-1, 0)];
+    let directions = [(0, -1), (1, 0), (0, 1), (-1, 0)];
     let mut best_score = 0;
 
     // iterate all positions
     for index in 0..grid.len() as i32 {
         let (x, y) = (index % width, index / width);
         let value = *grid.get(index as usize).unwrap();
-        let mut direction_score = vec![0, 0, 0, 0];
+        let mut direction_score = [0, 0, 0, 0];
 
         // iterate all directions per position
         for (i, (dx, dy)) in directions.iter().enumerate() {
@@ -104,8 +104,9 @@ pub fn part_two(_input: &str) -> Option<u32> {
     Some(best_score)
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 8);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 8);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -116,13 +117,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 8);
+        let input = advent_of_code::read_file("examples", 2022, 8);
         assert_eq!(part_one(&input), Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 8);
+        let input = advent_of_code::read_file("examples", 2022, 8);
         assert_eq!(part_two(&input), Some(8));
     }
 }
@@ -74,8 +74,9 @@ pub fn part_two(_input: &str) -> Option<u32> {
     Some(tracker.len() as u32)
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 9);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 9);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -86,13 +87,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 9);
+        let input = advent_of_code::read_file("examples", 2022, 9);
         assert_eq!(part_one(&input), Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 9);
+        let input = advent_of_code::read_file("examples", 2022, 9);
         assert_eq!(part_two(&input), Some(1));
     }
 }
@@ -1,5 +1,6 @@
 use std::{fmt, num::ParseIntError};
 
+use advent_of_code::ocr::Bitmap;
 use itertools::Itertools;
 
 // Instruction -----------------------------------------------------------------
@@ -22,7 +23,7 @@ impl Instruction {
 #[derive(Debug, Clone)]
 pub enum ExtractInstructionError {
     InvalidInstructionCode,
-    InvalidInteger(ParseIntError),
+    InvalidInteger(#[allow(dead_code)] ParseIntError),
 }
 
 impl From<ParseIntError> for ExtractInstructionError {
@@ -46,7 +47,7 @@ impl TryFrom<&str> for Instruction {
                 Ok(Instruction::AddX(x))
             }
             _ => {
-                println!("Error unknown instruction \"{:?}\"", value);
+                eprintln!("Error unknown instruction \"{:?}\"", value);
                 Err(ExtractInstructionError::InvalidInstructionCode)
             }
         }
@@ -60,6 +61,7 @@ struct Registers {
     x: i32,
 }
 
+#[allow(clippy::upper_case_acronyms)]
 struct CPU {
     last_instruction_clock: u32,
     clock: u32,
@@ -137,6 +139,7 @@ impl fmt::Display for CPU {
 
 // CRT -------------------------------------------------------------------------
 
+#[allow(clippy::upper_case_acronyms)]
 struct CRT {
     width: usize,
     height: usize,
@@ -236,7 +239,7 @@ pub fn part_one(input: &str) -> Option<i32> {
     Some(tracker)
 }
 
-pub fn part_two(input: &str) -> Option<i32> {
+pub fn part_two(input: &str) -> Option<Bitmap> {
     let instructions = input
         .lines()
         .map(|x| Instruction::try_from(x).unwrap())
@@ -245,12 +248,13 @@ pub fn part_two(input: &str) -> Option<i32> {
 
     while device.tick() {}
 
-    println!("{:#}", device);
-    Some(device.crt.screen.iter().sum::<u32>() as i32)
+    let pixels = device.crt.screen.iter().map(|p| *p > 0).collect();
+    Some(Bitmap::new(device.crt.width, device.crt.height, pixels))
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 10);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -261,13 +265,62 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file("examples", 2022, 10);
         assert_eq!(part_one(&input), Some(13140));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_two(&input), Some(124));
+        let input = advent_of_code::read_file("examples", 2022, 10);
+        let screen = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+        assert_eq!(
+            part_two(&input).map(|s| s.to_string()),
+            Some(screen.to_string())
+        );
+    }
+
+    /// A program that draws `art`. Each `addx` takes two cycles, which draw two pixels of a row
+    /// with the sprite at the position set by the previous `addx`. The sprite starts at 1, so the
+    /// first two pixels of `art` must be lit.
+    fn draw(art: &str) -> String {
+        let bitmap = Bitmap::from_ascii(art);
+        let mut sprite = 1;
+        let mut program = vec![];
+        for y in 0..6 {
+            for x in (0..40).step_by(2) {
+                let target = match (bitmap.get(x, y), bitmap.get(x + 1, y)) {
+                    (true, true) => x as i32,
+                    (true, false) => x as i32 - 1,
+                    (false, true) => x as i32 + 2,
+                    (false, false) => x as i32 + 5,
+                };
+                if y + x > 0 {
+                    program.push(format!("addx {}", target - sprite));
+                    sprite = target;
+                }
+            }
+        }
+        program.push("addx 0".to_string());
+        program.join("\n")
+    }
+
+    #[test]
+    fn test_part_two_letters() {
+        let art = "\
+####..###.###..#.....##...##..#..#..###.
+...#...#..#..#.#....#..#.#..#.#.#..#....
+..#....#..#..#.#....#..#.#....##...#....
+.#.....#..###..#....#..#.#....#.#...##..
+#......#..#....#....#..#.#..#.#.#.....#.
+####..###.#....####..##...##..#..#.###..";
+        let screen = part_two(&draw(art)).unwrap();
+        assert_eq!(format!("{:#}", screen), art);
+        assert_eq!(screen.to_string(), "ZIPLOCKS");
     }
 }
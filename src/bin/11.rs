@@ -53,7 +53,7 @@ impl Item {
 
     fn test(&self, test_parameter: u32, part2: bool) -> bool {
         if !part2 {
-            self.current_value % test_parameter == 0
+            self.current_value.is_multiple_of(test_parameter)
         } else {
             for mod_val in self.moduli.iter() {
                 if mod_val.0 == test_parameter {
@@ -246,8 +246,9 @@ pub fn part_two(input: &str) -> Option<u64> {
     )
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 11);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 11);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -258,13 +259,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = advent_of_code::read_file("examples", 2022, 11);
         assert_eq!(part_one(&input), Some(10605));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 11);
+        let input = advent_of_code::read_file("examples", 2022, 11);
         assert_eq!(part_two(&input), Some(2713310158));
     }
 }
@@ -5,7 +5,7 @@ use itertools::Itertools;
 
 type Coord2D = (isize, isize);
 
-fn neighbors(pos: Coord2D, grid: &Vec<Vec<u32>>) -> Vec<Coord2D> {
+fn neighbors(pos: Coord2D, grid: &[Vec<u32>]) -> Vec<Coord2D> {
     [(-1, 0), (1, 0), (0, -1), (0, 1)]
         .into_iter()
         .map(|d| add(pos, d))
@@ -20,7 +20,7 @@ fn add(coord: Coord2D, other: Coord2D) -> Coord2D {
 }
 
 fn pathfinding(
-    grid: &Vec<Vec<u32>>,
+    grid: &[Vec<u32>],
     start: Coord2D,
     f: impl Fn(Coord2D, u32) -> bool,
 ) -> Vec<Coord2D> {
@@ -103,6 +103,7 @@ fn parse_input(input: &str) -> (Vec<Vec<u32>>, Coord2D, Coord2D) {
     (grid, start, end)
 }
 
+#[allow(dead_code)]
 fn print_grid(grid: &[Vec<u32>], highlight: &[Coord2D]) {
     for (y, row) in grid.iter().enumerate() {
         for (x, col) in row.iter().enumerate() {
@@ -119,7 +120,6 @@ fn print_grid(grid: &[Vec<u32>], highlight: &[Coord2D]) {
 pub fn part_one(_input: &str) -> Option<u32> {
     let (grid, start, end) = parse_input(_input);
     let path = pathfinding(&grid, end, |pos, _| pos == start);
-    print_grid(&grid, &path);
 
     Some(path.len() as u32 - 1)
 }
@@ -129,13 +129,12 @@ pub fn part_two(_input: &str) -> Option<u32> {
     let (grid, _, end) = parse_input(_input);
     let path = pathfinding(&grid, end, |_, elevation| elevation == 0);
 
-    print_grid(&grid, &path);
-
     Some(path.len() as u32 - 1)
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 12);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 12);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -146,13 +145,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 12);
+        let input = advent_of_code::read_file("examples", 2022, 12);
         assert_eq!(part_one(&input), Some(31));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 12);
+        let input = advent_of_code::read_file("examples", 2022, 12);
         assert_eq!(part_two(&input), Some(29));
     }
 }
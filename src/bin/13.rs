@@ -135,8 +135,9 @@ pub fn part_two(_input: &str) -> Option<u32> {
     Some((index1 * index2) as u32)
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 13);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -147,13 +148,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = advent_of_code::read_file("examples", 2022, 13);
         assert_eq!(part_one(&input), Some(13));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
+        let input = advent_of_code::read_file("examples", 2022, 13);
         assert_eq!(part_two(&input), Some(140));
     }
 }
@@ -123,12 +123,9 @@ impl Grid {
                     return false;
                 }
 
-                match self.get_content_at_pos(&new_pos) {
-                    None => {
-                        sand_position = new_pos;
-                        continue 'outer;
-                    }
-                    Some(_) => {}
+                if self.get_content_at_pos(&new_pos).is_none() {
+                    sand_position = new_pos;
+                    continue 'outer;
                 }
             }
             self.draw_position(&sand_position, Some(Content::Sand));
@@ -202,8 +199,9 @@ pub fn part_two(_input: &str) -> Option<u32> {
     Some(tracker)
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 14);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 14);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -214,13 +212,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 14);
+        let input = advent_of_code::read_file("examples", 2022, 14);
         assert_eq!(part_one(&input), Some(24));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 14);
+        let input = advent_of_code::read_file("examples", 2022, 14);
         assert_eq!(part_two(&input), Some(93));
     }
 }
@@ -61,8 +61,8 @@ fn calculate_ranges_of_row_where_no_beacon_is_possible(
             continue;
         }
         let width = 2 * (*radius as isize - scanner_row_distance) - 1;
-        let mut start = scanner.x - ((width as isize - 1) / 2);
-        let mut end = start + width as isize;
+        let mut start = scanner.x - ((width - 1) / 2);
+        let mut end = start + width;
 
         if let Some((min, max)) = clamp {
             start = start.max(min);
@@ -75,7 +75,7 @@ fn calculate_ranges_of_row_where_no_beacon_is_possible(
         ranges.push((start, end));
     }
 
-    ranges.sort_by(|a, b| a.0.cmp(&b.0));
+    ranges.sort_by_key(|a| a.0);
     ranges.reverse();
 
     let mut merged_ranges: Vec<(isize, isize)> = vec![];
@@ -96,11 +96,15 @@ fn calculate_ranges_of_row_where_no_beacon_is_possible(
     merged_ranges
 }
 
-pub fn part_one(_input: &str) -> Option<u32> {
-    part_one_param(_input, 2000000)
+advent_of_code::params! {
+    pub struct Params {
+        row: isize = { real: 2000000, example: 10 },
+        search_size: isize = { real: 4000000, example: 20 },
+    }
 }
 
-pub fn part_one_param(_input: &str, row: isize) -> Option<u32> {
+pub fn part_one(_input: &str, params: &Params) -> Option<u32> {
+    let row = params.row;
     let sbmap = parse_input(_input);
     let mut tracker = 0;
     let ranges: Vec<(isize, isize)> =
@@ -121,11 +125,8 @@ pub fn part_one_param(_input: &str, row: isize) -> Option<u32> {
     Some(tracker as u32)
 }
 
-pub fn part_two(_input: &str) -> Option<u64> {
-    part_two_param(_input, 4000000)
-}
-
-pub fn part_two_param(_input: &str, search_size: isize) -> Option<u64> {
+pub fn part_two(_input: &str, params: &Params) -> Option<u64> {
+    let search_size = params.search_size;
     let sbmap = parse_input(_input);
 
     let mut tracker: u64 = 0;
@@ -140,25 +141,27 @@ pub fn part_two_param(_input: &str, search_size: isize) -> Option<u64> {
     Some(tracker)
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 15);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 15);
+    advent_of_code::solve!(1, part_one, input, Params);
+    advent_of_code::solve!(2, part_two, input, Params);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::params::Params as _;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 15);
-        assert_eq!(part_one_param(&input, 10), Some(26));
+        let input = advent_of_code::read_file("examples", 2022, 15);
+        assert_eq!(part_one(&input, &Params::example()), Some(26));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 15);
-        assert_eq!(part_two_param(&input, 20), Some(56000011));
+        let input = advent_of_code::read_file("examples", 2022, 15);
+        assert_eq!(part_two(&input, &Params::example()), Some(56000011));
     }
 }
@@ -27,7 +27,7 @@ fn parse_input_into_nodes(input: &str) -> (Vec<Node>, usize) {
         });
     }
 
-    result.sort_by(|a, b| b.flow.cmp(&a.flow));
+    result.sort_by_key(|a| std::cmp::Reverse(a.flow));
 
     for i in 0..result.len() {
         if result[i].name == "AA" {
@@ -151,8 +151,9 @@ pub fn part_two(_input: &str) -> Option<u32> {
     Some(tracker)
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 16);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 16);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -163,13 +164,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 16);
+        let input = advent_of_code::read_file("examples", 2022, 16);
         assert_eq!(part_one(&input), Some(1651));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 16);
+        let input = advent_of_code::read_file("examples", 2022, 16);
         assert_eq!(part_two(&input), Some(1707));
     }
 }
@@ -205,6 +205,7 @@ fn check_cycles(rocks: &[Rock]) -> Option<(i64, i64, i64)> {
     None
 }
 
+#[allow(dead_code)]
 fn print_chamber(rocks: &[Rock], current_rock: Option<&Rock>) {
     let height = rocks
         .iter()
@@ -217,18 +218,9 @@ fn print_chamber(rocks: &[Rock], current_rock: Option<&Rock>) {
         print!("|");
         for x in 0..7 {
             let position = Point { x, y };
-            if current_rock.is_some()
-                && current_rock
-                    .unwrap()
-                    .real_points()
-                    .iter()
-                    .any(|p| *p == position)
-            {
+            if current_rock.is_some() && current_rock.unwrap().real_points().contains(&position) {
                 print!("@");
-            } else if rocks
-                .iter()
-                .any(|r| r.real_points().iter().any(|p| *p == position))
-            {
+            } else if rocks.iter().any(|r| r.real_points().contains(&position)) {
                 print!("#");
             } else {
                 print!(".");
@@ -317,8 +309,9 @@ pub fn part_two(_input: &str) -> Option<u64> {
     Some(total_height as u64)
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 17);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 17);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -329,13 +322,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 17);
+        let input = advent_of_code::read_file("examples", 2022, 17);
         assert_eq!(part_one(&input), Some(3068));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 17);
+        let input = advent_of_code::read_file("examples", 2022, 17);
         assert_eq!(part_two(&input), Some(1514285714288));
     }
 }
@@ -104,8 +104,9 @@ pub fn part_two(_input: &str) -> Option<u32> {
     Some(count_open_sides(&points) - count_open_sides(&inside))
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 18);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 18);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -116,13 +117,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 18);
+        let input = advent_of_code::read_file("examples", 2022, 18);
         assert_eq!(part_one(&input), Some(64));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 18);
+        let input = advent_of_code::read_file("examples", 2022, 18);
         assert_eq!(part_two(&input), Some(58));
     }
 }
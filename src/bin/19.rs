@@ -51,10 +51,8 @@ impl State {
                     return None;
                 }
                 Some(
-                    (0.max(costs.ore_robot.0 as i32 - self.currency.0 as i32) as u32
-                        + self.amount_robots.0
-                        - 1)
-                        / self.amount_robots.0,
+                    (0.max(costs.ore_robot.0 as i32 - self.currency.0 as i32) as u32)
+                        .div_ceil(self.amount_robots.0),
                 )
             }
             RobotType::Clay => {
@@ -62,10 +60,8 @@ impl State {
                     return None;
                 }
                 Some(
-                    (0.max(costs.clay_robot.0 as i32 - self.currency.0 as i32) as u32
-                        + self.amount_robots.0
-                        - 1)
-                        / self.amount_robots.0,
+                    (0.max(costs.clay_robot.0 as i32 - self.currency.0 as i32) as u32)
+                        .div_ceil(self.amount_robots.0),
                 )
             }
             RobotType::Obsidian => {
@@ -76,30 +72,22 @@ impl State {
                     return None;
                 }
                 let time_ore = (0.max(costs.obsidian_robot.0 as i32 - self.currency.0 as i32)
-                    as u32
-                    + self.amount_robots.0
-                    - 1)
-                    / self.amount_robots.0;
+                    as u32)
+                    .div_ceil(self.amount_robots.0);
                 let time_clay = (0.max(costs.obsidian_robot.1 as i32 - self.currency.1 as i32)
-                    as u32
-                    + self.amount_robots.1
-                    - 1)
-                    / self.amount_robots.1;
+                    as u32)
+                    .div_ceil(self.amount_robots.1);
                 Some(time_ore.max(time_clay))
             }
             RobotType::Geode => {
                 if self.amount_robots.0 == 0 || self.amount_robots.2 == 0 {
                     return None;
                 }
-                let time_ore = (0.max(costs.geode_robot.0 as i32 - self.currency.0 as i32) as u32
-                    + self.amount_robots.0
-                    - 1)
-                    / self.amount_robots.0;
+                let time_ore = (0.max(costs.geode_robot.0 as i32 - self.currency.0 as i32) as u32)
+                    .div_ceil(self.amount_robots.0);
                 let time_obsidian = (0.max(costs.geode_robot.2 as i32 - self.currency.2 as i32)
-                    as u32
-                    + self.amount_robots.2
-                    - 1)
-                    / self.amount_robots.2;
+                    as u32)
+                    .div_ceil(self.amount_robots.2);
                 Some(time_ore.max(time_obsidian))
             }
         }
@@ -275,7 +263,14 @@ fn find_optimum_return_recursivly(
     max_geodes
 }
 
-pub fn part_one(_input: &str) -> Option<u32> {
+advent_of_code::params! {
+    pub struct Params {
+        /// Part two only uses the first `blueprints` blueprints.
+        blueprints: usize = { real: 3, example: 2 },
+    }
+}
+
+pub fn part_one(_input: &str, _params: &Params) -> Option<u32> {
     let blueprints = parse_input(_input);
     let mut tracker = 0;
     for (i, b) in blueprints.iter().enumerate() {
@@ -294,10 +289,10 @@ pub fn part_one(_input: &str) -> Option<u32> {
     Some(tracker)
 }
 
-pub fn part_two(_input: &str) -> Option<u32> {
-    let blueprints = parse_input(_input.lines().take(3).join("\n").as_str());
+pub fn part_two(_input: &str, params: &Params) -> Option<u32> {
+    let blueprints = parse_input(_input.lines().take(params.blueprints).join("\n").as_str());
     let mut tracker = 1;
-    for (_, b) in blueprints.iter().enumerate() {
+    for b in blueprints.iter() {
         let result = find_optimum_return_recursivly(
             32,
             b,
@@ -313,26 +308,28 @@ pub fn part_two(_input: &str) -> Option<u32> {
     Some(tracker)
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 19);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 19);
+    advent_of_code::solve!(1, part_one, input, Params);
+    advent_of_code::solve!(2, part_two, input, Params);
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent_of_code::params::Params as _;
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 19);
-        assert_eq!(part_one(&input), Some(33));
+        let input = advent_of_code::read_file("examples", 2022, 19);
+        assert_eq!(part_one(&input, &Params::example()), Some(33));
     }
 
     #[ignore]
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 19);
-        assert_eq!(part_two(&input), Some(56 * 62));
+        let input = advent_of_code::read_file("examples", 2022, 19);
+        assert_eq!(part_two(&input, &Params::example()), Some(56 * 62));
     }
 }
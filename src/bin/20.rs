@@ -39,6 +39,7 @@ impl IndexedList {
         }
     }
 
+    #[allow(dead_code)]
     fn return_numbers_in_order(&self) -> Vec<i64> {
         self.numbers
             .iter()
@@ -118,8 +119,9 @@ pub fn part_two(_input: &str) -> Option<i64> {
     Some(x + y + z)
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 20);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 20);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -130,13 +132,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 20);
+        let input = advent_of_code::read_file("examples", 2022, 20);
         assert_eq!(part_one(&input), Some(3));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 20);
+        let input = advent_of_code::read_file("examples", 2022, 20);
         assert_eq!(part_two(&input), Some(1623178306));
     }
 }
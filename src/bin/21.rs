@@ -367,8 +367,9 @@ pub fn part_two(_input: &str) -> Option<i64> {
     }
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 21);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 21);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -379,13 +380,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 21);
+        let input = advent_of_code::read_file("examples", 2022, 21);
         assert_eq!(part_one(&input), Some(152));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 21);
+        let input = advent_of_code::read_file("examples", 2022, 21);
         assert_eq!(part_two(&input), Some(301));
     }
 }
@@ -1,10 +1,6 @@
-use std::{
-    collections::HashMap,
-    io::{self, Read},
-};
+use std::collections::HashMap;
 
 use advent_of_code::helpers::{Point, PointDirection, PointGrid};
-use itertools::Itertools;
 use parse_display::Display;
 
 // Urgh, this day is even worse programming from my part. I made the decision
@@ -180,6 +176,7 @@ fn do_action(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn do_action_cube(
     face_grid: &PointGrid<usize>,
     faces: &[PointGrid<Content>],
@@ -468,8 +465,7 @@ fn wrap_around_cube(
         return (*new_face, new_pos, facing);
     }
 
-    println!("Not implemented, for {} in direction {}", face_pos, facing);
-    unimplemented!()
+    unimplemented!("for {} in direction {}", face_pos, facing)
 }
 
 fn calculate_flipped_wrap(
@@ -562,13 +558,13 @@ fn calc_password(pos: &Point<isize>, facing: &PointDirection) -> isize {
     1000 * (pos.y + 1) + 4 * (pos.x + 1) + facing_points
 }
 
+#[allow(dead_code)]
 fn print_grid_with_path(
     grid: &PointGrid<Content>,
     path: &[(Point<isize>, PointDirection)],
 ) -> String {
     let (min, max) = grid.dimensions();
-    let mut result = String::new();
-    result = format!("Grid ({}, {}): \n", min, max);
+    let mut result = format!("Grid ({}, {}): \n", min, max);
     for y in min.y..(max.y + 1) {
         for x in min.x..(max.x + 1) {
             if let Some((_, direction)) = path.iter().find(|(p, _)| *p == Point { x, y }) {
@@ -585,6 +581,7 @@ fn print_grid_with_path(
     result
 }
 
+#[allow(dead_code)]
 fn print_grid_with_path_cubed(
     grid: &PointGrid<Content>,
     face_grid: &PointGrid<usize>,
@@ -717,8 +714,9 @@ pub fn part_two(_input: &str) -> Option<isize> {
     Some(calc_password(&calc_pos, &facing))
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 22);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 22);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -729,13 +727,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 22);
+        let input = advent_of_code::read_file("examples", 2022, 22);
         assert_eq!(part_one(&input), Some(6032));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 22);
+        let input = advent_of_code::read_file("examples", 2022, 22);
         assert_eq!(part_two(&input), Some(5031));
     }
 }
@@ -212,8 +212,9 @@ pub fn part_two(_input: &str) -> Option<isize> {
     Some(tracker)
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 23);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 23);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -224,14 +225,14 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 23);
+        let input = advent_of_code::read_file("examples", 2022, 23);
         assert_eq!(part_one(&input), Some(110));
     }
 
     #[ignore]
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 23);
+        let input = advent_of_code::read_file("examples", 2022, 23);
         assert_eq!(part_two(&input), Some(20));
     }
 }
@@ -209,8 +209,9 @@ pub fn part_two(_input: &str) -> Option<usize> {
     pathfinding(&bl_cache, &start, &end, goal2, cycle_length, max_distance)
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 24);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 24);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -221,13 +222,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 24);
+        let input = advent_of_code::read_file("examples", 2022, 24);
         assert_eq!(part_one(&input), Some(18));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 24);
+        let input = advent_of_code::read_file("examples", 2022, 24);
         assert_eq!(part_two(&input), Some(54));
     }
 }
@@ -1,5 +1,3 @@
-use std::fmt;
-
 fn convert_into_snafu(input: usize) -> String {
     let base: usize = 5;
     let mut result = String::new();
@@ -49,7 +47,6 @@ fn convert_snafu_digit_to_digit(input: char) -> isize {
 
 pub fn part_one(_input: &str) -> Option<String> {
     let sum: usize = _input.lines().map(convert_from_snafu).sum();
-    println!("Result decimal: {}", sum);
     Some(convert_into_snafu(sum))
 }
 
@@ -58,8 +55,9 @@ pub fn part_two(_input: &str) -> Option<u32> {
     Some(0)
 }
 
+#[allow(dead_code)]
 fn main() {
-    let input = &advent_of_code::read_file("inputs", 25);
+    let input = &advent_of_code::try_read_file("inputs", 2022, 25);
     advent_of_code::solve!(1, part_one, input);
     advent_of_code::solve!(2, part_two, input);
 }
@@ -70,13 +68,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 25);
+        let input = advent_of_code::read_file("examples", 2022, 25);
         assert_eq!(part_one(&input), Some("2=-1=0".to_string()));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 25);
+        let input = advent_of_code::read_file("examples", 2022, 25);
         assert_eq!(part_two(&input), Some(0));
     }
 }
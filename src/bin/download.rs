@@ -2,105 +2,199 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
-use std::{fs, process};
+use advent_of_code::client::{self, Client};
+use advent_of_code::config;
+use advent_of_code::input;
+use advent_of_code::manifest::Manifest;
+use advent_of_code::puzzle;
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
 
 struct Args {
-    day: u8,
-    year: Option<i16>,
+    day: Option<u8>,
+    year: u16,
+    all: bool,
+    delay: Duration,
+    force: bool,
+    puzzle_only: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
-        day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(config::default_year),
+        all: args.contains("--all"),
+        delay: args
+            .opt_value_from_str("--delay")?
+            .map_or(Duration::from_secs(5), Duration::from_secs_f64),
+        force: args.contains(["-f", "--force"]),
+        puzzle_only: args.contains("--puzzle-only"),
+        day: args.opt_free_from_str()?,
     })
 }
 
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
+/// Spaces out requests, so downloading many days does not hammer the server.
+struct Throttle {
+    delay: Duration,
+    last: Option<Instant>,
+}
+
+impl Throttle {
+    fn wait(&mut self) {
+        if let Some(last) = self.last {
+            thread::sleep(self.delay.saturating_sub(last.elapsed()));
+        }
+        self.last = Some(Instant::now());
     }
 }
 
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
+fn write_file(path: &Path, contents: &str) -> Result<(), String> {
+    fs::create_dir_all(path.parent().unwrap())
+        .map_err(|e| format!("could not create directory: {}", e))?;
+    input::write_atomic(path, contents)
+        .map_err(|e| format!("could not write \"{}\": {}", path.display(), e))
 }
 
-fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
+fn lock(path: &Path) -> Result<fs::File, String> {
+    fs::create_dir_all(path.parent().unwrap())
+        .map_err(|e| format!("could not create directory: {}", e))?;
+    input::lock(path).map_err(|e| format!("could not lock \"{}\": {}", path.display(), e))
+}
 
-    let args = match parse_args() {
-        Ok(args) => args,
-        Err(e) => {
-            eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
-        }
-    };
+fn download_input(client: &Client, year: u16, day: u8, force: bool) -> Result<(), String> {
+    let input_path = advent_of_code::input_path("inputs", year, day);
+    // held until the manifest is updated, so concurrent downloads of a day run one after another.
+    let _lock = lock(&input_path)?;
+    if !force && input::has_contents(&input_path) {
+        return Err(format!(
+            "\"{}\" already exists. Use --force to download it again, or --puzzle-only to only update the puzzle.",
+            input_path.display()
+        ));
+    }
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
+    println!(
+        "Downloading input of day {} of {} from {}",
+        day,
+        year,
+        client.base_url()
+    );
+    let contents = client
+        .input(year, day)
+        .map_err(|e| format!("Failed to download input: {}", e))?;
+    write_file(&input_path, &contents)?;
 
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
-    }
+    let manifest_path = Manifest::path(year);
+    let _manifest_lock = lock(&manifest_path)?;
+    Manifest::load(&manifest_path)
+        .and_then(|mut manifest| {
+            manifest.record(year, day, &contents, Some(client.account()));
+            manifest.save(&manifest_path)
+        })
+        .map_err(|e| format!("could not update \"{}\": {}", manifest_path.display(), e))?;
+
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
+    Ok(())
+}
+
+/// The description is downloaded again every time, as part two only shows up after part one is
+/// solved. The example is only written if there is none yet.
+fn download_puzzle(client: &Client, year: u16, day: u8) -> Result<(), String> {
+    let page = client
+        .puzzle(year, day)
+        .map_err(|e| format!("Failed to download puzzle: {}", e))?;
 
-    let mut cmd_args = vec![];
+    let puzzle_path = puzzle::puzzle_path(year, day);
+    write_file(&puzzle_path, &puzzle::to_markdown(&page))?;
+    println!(
+        "🎄 Successfully wrote puzzle to \"{}\".",
+        puzzle_path.display()
+    );
 
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
+    let example_path = advent_of_code::input_path("examples", year, day);
+    if input::has_contents(&example_path) {
+        return Ok(());
+    }
+    match puzzle::extract_example(&page) {
+        Some(example) => {
+            write_file(&example_path, &example)?;
+            println!(
+                "🎄 Successfully wrote example to \"{}\", check that it is the right one.",
+                example_path.display()
+            );
+        }
+        None => println!("Could not find an example in the puzzle, add it by hand."),
     }
+    Ok(())
+}
+
+/// Downloads every unlocked day without an input or puzzle, one after another. Files that are
+/// already downloaded are skipped, so an interrupted run resumes where it stopped.
+fn download_all(client: &Client, args: &Args) -> Result<(), String> {
+    let mut throttle = Throttle {
+        delay: args.delay,
+        last: None,
+    };
+    let now = SystemTime::now();
 
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "--input-only".into(),
-        "download".into(),
-    ]);
-
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
-
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
+    for day in (1..=25).filter(|day| client::unlock_time(args.year, *day) <= now) {
+        let has_input = input::has_contents(&advent_of_code::input_path("inputs", args.year, day));
+        let has_puzzle = puzzle::puzzle_path(args.year, day).exists();
+        if has_input && has_puzzle && !args.force && !args.puzzle_only {
+            println!("Day {} is already downloaded.", day);
+            continue;
         }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+
+        println!("---");
+        if !args.puzzle_only && (!has_input || args.force) {
+            throttle.wait();
+            download_input(client, args.year, day, args.force)?;
         }
+        throttle.wait();
+        download_puzzle(client, args.year, day)?;
     }
+    Ok(())
+}
 
-    match fs::copy(&tmp_file_path, &input_path) {
+fn main() {
+    let args = match parse_args() {
+        Ok(args) if args.all != args.day.is_some() => args,
         Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
+            eprintln!("Need to specify either a day or --all. example: `cargo download 7`");
+            process::exit(1);
         }
         Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let client = match Client::from_config() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    let result = match args.day {
+        Some(day) if args.puzzle_only => download_puzzle(&client, args.year, day),
+        Some(day) => download_input(&client, args.year, day, args.force)
+            .and_then(|_| download_puzzle(&client, args.year, day)),
+        None => download_all(&client, &args),
+    };
+    if let Err(e) = result {
+        eprintln!("{}", e);
+        if args.all {
+            eprintln!("Run `cargo download --all` again to resume.");
         }
+        process::exit(1);
     }
 }
@@ -0,0 +1,83 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::client;
+use advent_of_code::config::{self, Config};
+use advent_of_code::manifest::{self, InputStatus, Manifest};
+use advent_of_code::{ANSI_BOLD, ANSI_RESET};
+use std::fs;
+use std::process;
+
+struct Args {
+    command: String,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(config::default_year),
+        command: args.free_from_str()?,
+    })
+}
+
+fn status(year: u16) {
+    let manifest_path = Manifest::path(year);
+    let manifest = match Manifest::load(&manifest_path) {
+        Ok(manifest) => manifest,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", manifest_path.display(), e);
+            process::exit(1);
+        }
+    };
+    // without a session, inputs are not compared by account.
+    let account = Config::load()
+        .ok()
+        .and_then(|config| client::session(&config))
+        .map(|session| manifest::account_id(&session));
+
+    println!("| Day | Status | Downloaded |");
+    println!("| :---: | :---: | :---: |");
+    let mut problems = 0;
+    for day in 1..=25 {
+        let contents = fs::read(advent_of_code::input_path("inputs", year, day)).ok();
+        let status = manifest.status(year, day, contents.as_deref(), account.as_deref());
+        if status != InputStatus::Ok {
+            problems += 1;
+        }
+        let downloaded = manifest
+            .get(year, day)
+            .map_or("-".to_string(), |e| manifest::format_utc(e.downloaded_at));
+        println!("| {:02} | {} | {} |", day, status.description(), downloaded);
+    }
+
+    println!("---");
+    match problems {
+        0 => println!("🎄 All inputs of {} are downloaded and unchanged.", year),
+        _ => println!(
+            "{}{} input(s) of {} need attention.{}",
+            ANSI_BOLD, problems, year, ANSI_RESET
+        ),
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a command. example: `cargo inputs status`");
+            process::exit(1);
+        }
+    };
+
+    match args.command.as_str() {
+        "status" => status(args.year),
+        command => {
+            eprintln!("Unknown command \"{}\", expected: status", command);
+            process::exit(1);
+        }
+    }
+}
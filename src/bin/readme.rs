@@ -0,0 +1,80 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::Answers;
+use advent_of_code::output::Record;
+use advent_of_code::runner::{self, Options};
+use advent_of_code::{config, days, input, readme};
+use std::collections::BTreeMap;
+use std::fs;
+use std::process;
+use std::time::Duration;
+
+struct Args {
+    year: u16,
+    options: Options,
+    jobs: usize,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let mut options = Options::from_args(&mut args)?;
+    options.timeout = args
+        .opt_value_from_str("--timeout")?
+        .map(Duration::from_secs_f64);
+    Ok(Args {
+        options,
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(config::default_year),
+        jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+
+    let readme_path = advent_of_code::project_dir().join("README.md");
+    let contents = match fs::read_to_string(&readme_path) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", readme_path.display(), e);
+            process::exit(1);
+        }
+    };
+
+    println!("Running all days of {}...", args.year);
+    let solutions = days::select(args.year, None);
+    let mut records: Vec<Record> = vec![];
+    runner::run_days(&solutions, &args.options, args.jobs, |_, day_records| {
+        records.extend(day_records)
+    });
+
+    let accepted = solutions
+        .iter()
+        .filter_map(|s| Answers::read(s.year, s.day).map(|answers| (s.day, answers)))
+        .collect::<BTreeMap<_, _>>();
+    let table = readme::results_table(args.year, &records, &accepted);
+    let contents = match readme::replace_block(&contents, &table) {
+        Ok(contents) => contents,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    if let Err(e) = input::write_atomic(&readme_path, &contents) {
+        eprintln!("could not write \"{}\": {}", readme_path.display(), e);
+        process::exit(1);
+    }
+    println!(
+        "🎄 Updated the timings table in \"{}\".",
+        readme_path.display()
+    );
+}
@@ -2,71 +2,180 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::config;
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(_input: &str) -> Option<u32> {
-    None
-}
+/// Used without `--template`. Templates are the files in `templates/`, e.g. `grid` for `grid.rs`.
+const DEFAULT_TEMPLATE: &str = "lines";
 
-pub fn part_two(_input: &str) -> Option<u32> {
-    None
+struct Args {
+    day: u8,
+    year: u16,
+    template: String,
+    answer: String,
+    /// Number of example files, `None` for a single `NN.txt`.
+    examples: Option<u8>,
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(config::default_year),
+        template: args
+            .opt_value_from_str(["-t", "--template"])?
+            .unwrap_or_else(|| DEFAULT_TEMPLATE.to_string()),
+        answer: args
+            .opt_value_from_str("--answer")?
+            .unwrap_or_else(|| "u32".to_string()),
+        examples: args
+            .opt_value_from_str("--examples")?
+            .filter(|examples| *examples > 0),
+        day: args.free_from_str()?,
+    })
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn read_template(name: &str) -> Result<String, String> {
+    let path = advent_of_code::project_dir()
+        .join("templates")
+        .join(format!("{}.rs", name));
+    fs::read_to_string(&path).map_err(|e| format!("could not read \"{}\": {}", path.display(), e))
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
-    }
+/// Renders the module from the template `name`, with the test stubs for single or numbered
+/// examples from `templates/tests/`. Templates whose solvers take more arguments, like `params`,
+/// have their own test stubs in `templates/tests/<name>/`.
+fn render_module(
+    name: &str,
+    mut values: Vec<(&str, String)>,
+    examples: bool,
+) -> Result<String, String> {
+    let tests_dir = match advent_of_code::project_dir()
+        .join("templates/tests")
+        .join(name)
+        .is_dir()
+    {
+        true => format!("tests/{}", name),
+        false => "tests".to_string(),
+    };
+    let tests = match examples {
+        true => read_template(&format!("{}/examples", tests_dir))?,
+        false => read_template(&format!("{}/single", tests_dir))?,
+    };
+    let tests = render(tests.trim_end(), &values)?;
+    values.push(("tests", tests));
+    render(&read_template(name)?, &values)
+}
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+/// Contents of `NN_answers.toml` for a new day, see `advent_of_code::answers::check_examples`.
+fn example_answers(day: u8, examples: u8) -> String {
+    let mut contents = format!(
+        "# Expected answers for the examples of day {}, checked by `test_examples`.\n\
+         # Answers are numbers or strings, leave out the ones that are not known yet.\n",
+        day
+    );
+    for example in 1..=examples {
+        contents.push_str(&format!(
+            "\n[{:02}]\n# part_one = 0\n# part_two = 0\n",
+            example
+        ));
     }
+    contents
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+/// Replaces the `{{name}}` placeholders of a template. Fails on unknown placeholders, so typos
+/// in a template do not end up in the module.
+fn render(template: &str, values: &[(&str, String)]) -> Result<String, String> {
+    let mut rendered = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..]
+            .find("}}")
+            .ok_or_else(|| "unclosed placeholder".to_string())?;
+        let name = rest[start + 2..start + end].trim();
+        let value = values
+            .iter()
+            .find(|(key, _)| *key == name)
+            .ok_or_else(|| format!("unknown placeholder \"{}\"", name))?;
+        rendered.push_str(&rest[..start]);
+        rendered.push_str(&value.1);
+        rest = &rest[start + end + 2..];
+    }
+    rendered.push_str(rest);
+    Ok(rendered)
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
+fn safe_create_file(path: &Path) -> Result<File, std::io::Error> {
     OpenOptions::new().write(true).create_new(true).open(path)
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+fn create_file(path: &Path) -> Result<File, std::io::Error> {
+    OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(path)
 }
 
 fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
+    let Args {
+        day,
+        year,
+        template,
+        answer,
+        examples,
+    } = match parse_args() {
+        Ok(args) => args,
         Err(_) => {
             eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
             process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", day);
+    let bin_name = advent_of_code::bin_name(year, day);
+
+    let input_path = advent_of_code::input_path("inputs", year, day);
+    let example_paths = match examples {
+        Some(examples) => (1..=examples)
+            .map(|example| {
+                advent_of_code::year_dir("examples", year)
+                    .join(format!("{:02}_{:02}.txt", day, example))
+            })
+            .collect(),
+        None => vec![advent_of_code::input_path("examples", year, day)],
+    };
+    let module_path = advent_of_code::project_dir()
+        .join("src")
+        .join("bin")
+        .join(format!("{}.rs", bin_name));
+
+    for (folder, path) in [("inputs", &input_path), ("examples", &example_paths[0])] {
+        if let Err(e) = fs::create_dir_all(path.parent().unwrap()) {
+            eprintln!("Failed to create {} directory: {}", folder, e);
+            process::exit(1);
+        }
+    }
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+    let values = vec![
+        ("day", day.to_string()),
+        ("day_padded", format!("{:02}", day)),
+        ("year", year.to_string()),
+        ("answer", answer),
+    ];
+    let module = render_module(&template, values, examples.is_some());
+    let module = match module {
+        Ok(module) => module,
+        Err(e) => {
+            eprintln!("Failed to render template \"{}\": {}", template, e);
+            process::exit(1);
+        }
+    };
 
     let mut file = match safe_create_file(&module_path) {
         Ok(file) => file,
@@ -76,9 +185,9 @@ fn main() {
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
+    match file.write_all(module.as_bytes()) {
         Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+            println!("Created module file \"{}\"", module_path.display());
         }
         Err(e) => {
             eprintln!("Failed to write module contents: {}", e);
@@ -88,7 +197,7 @@ fn main() {
 
     match create_file(&input_path) {
         Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+            println!("Created empty input file \"{}\"", input_path.display());
         }
         Err(e) => {
             eprintln!("Failed to create input file: {}", e);
@@ -96,19 +205,108 @@ fn main() {
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    for example_path in example_paths.iter() {
+        match create_file(example_path) {
+            Ok(_) => {
+                println!("Created empty example file \"{}\"", example_path.display());
+            }
+            Err(e) => {
+                eprintln!("Failed to create example file: {}", e);
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
+    }
+
+    if let Some(examples) = examples {
+        let answers_path = advent_of_code::answers::example_answers_path(year, day);
+        match safe_create_file(&answers_path) {
+            Ok(mut file) => match file.write_all(example_answers(day, examples).as_bytes()) {
+                Ok(_) => println!(
+                    "Created expected answers file \"{}\"",
+                    answers_path.display()
+                ),
+                Err(e) => {
+                    eprintln!("Failed to write expected answers: {}", e);
+                    process::exit(1);
+                }
+            },
+            // keeps the answers of an earlier scaffold.
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {}
+            Err(e) => {
+                eprintln!("Failed to create expected answers file: {}", e);
+                process::exit(1);
+            }
         }
     }
 
     println!("---");
     println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        "🎄 Type `cargo solve {}` to run your solution. Add it to `src/days.rs` to include it in `cargo all`.",
+        &bin_name
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::answers::{example_failures, parse_example_answers};
+
+    #[test]
+    fn test_render() {
+        let values = [("day", "7".to_string()), ("answer", "u64".to_string())];
+        assert_eq!(
+            render(
+                "read_file(\"examples\", {{day}}) -> Option<{{ answer }}>",
+                &values
+            ),
+            Ok("read_file(\"examples\", 7) -> Option<u64>".to_string())
+        );
+        assert_eq!(
+            render("{{year}}", &values),
+            Err("unknown placeholder \"year\"".to_string())
+        );
+        assert_eq!(
+            render("{{day", &values),
+            Err("unclosed placeholder".to_string())
+        );
+    }
+
+    #[test]
+    fn test_scaffolded_example_answers() {
+        let examples = parse_example_answers(&example_answers(27, 2)).unwrap();
+        assert_eq!(examples.len(), 2);
+        let failures = example_failures(
+            &examples,
+            |example| panic!("example {} should not be read", example),
+            |_| None::<u32>,
+            |_| None::<u32>,
+        );
+        assert!(failures.is_empty());
+    }
+
+    #[test]
+    fn test_templates() {
+        let values = vec![
+            ("day", "1".to_string()),
+            ("day_padded", "01".to_string()),
+            ("year", "2022".to_string()),
+            ("answer", "u32".to_string()),
+        ];
+        for entry in fs::read_dir(advent_of_code::project_dir().join("templates")).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_file() {
+                let name = path.file_stem().unwrap().to_str().unwrap();
+                for examples in [false, true] {
+                    assert!(render_module(name, values.clone(), examples).is_ok());
+                }
+            }
+        }
+
+        for examples in [false, true] {
+            let module = render_module("params", values.clone(), examples).unwrap();
+            assert!(module.contains("advent_of_code::params! {"));
+            assert!(module.contains("advent_of_code::solve!(2, part_two, input, Params);"));
+            assert!(module.contains("&Params::example()") || module.contains("Params::example();"));
+        }
+    }
+}
@@ -0,0 +1,121 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::client::Client;
+use advent_of_code::config;
+use advent_of_code::days;
+use advent_of_code::submit::{History, Outcome};
+use advent_of_code::{ANSI_BOLD, ANSI_RESET};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: u16,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(config::default_year),
+        day: args.free_from_str()?,
+        part: args.free_from_str()?,
+    })
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) if args.part == 1 || args.part == 2 => args,
+        _ => {
+            eprintln!("Need to specify a day and a part (1 or 2). example: `cargo submit 7 1`");
+            process::exit(1);
+        }
+    };
+
+    let solution = match days::get(args.year, args.day) {
+        Some(solution) => solution,
+        None => {
+            eprintln!(
+                "Day {} of {} is not registered in src/days.rs.",
+                args.day, args.year
+            );
+            process::exit(1);
+        }
+    };
+    let input = match advent_of_code::try_read_file("inputs", args.year, args.day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+    let (_, solver) = solution.parts()[args.part as usize - 1];
+    let answer = match solver(&input) {
+        Some(answer) => answer,
+        None => {
+            eprintln!("Part {} of day {} is not solved yet.", args.part, args.day);
+            process::exit(1);
+        }
+    };
+    if answer.contains('\n') {
+        eprintln!(
+            "The answer spans multiple lines, submit it by hand:\n{}",
+            answer
+        );
+        process::exit(1);
+    }
+
+    let history_path = History::path(args.year);
+    let mut history = match History::load(&history_path) {
+        Ok(history) => history,
+        Err(e) => {
+            eprintln!("Failed to read \"{}\": {}", history_path.display(), e);
+            process::exit(1);
+        }
+    };
+    if let Err(e) = history.check(args.year, args.day, args.part, &answer) {
+        eprintln!("Not submitting \"{}\": {}", answer, e);
+        process::exit(1);
+    }
+
+    let client = match Client::from_config() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    };
+
+    println!(
+        "Submitting \"{}\" for day {} part {} of {} to {}",
+        answer,
+        args.day,
+        args.part,
+        args.year,
+        client.base_url()
+    );
+    let outcome = match client.submit(args.year, args.day, args.part, &answer) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("Failed to submit answer: {}", e);
+            process::exit(1);
+        }
+    };
+
+    history.record(args.year, args.day, args.part, &answer, outcome.clone());
+    if let Err(e) = history.save(&history_path) {
+        eprintln!("could not update \"{}\": {}", history_path.display(), e);
+    }
+
+    println!("---");
+    match outcome {
+        Outcome::Correct => println!("🎄 {}{}{}", ANSI_BOLD, outcome, ANSI_RESET),
+        _ => {
+            println!("{}", outcome);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,72 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::{Answers, Verdict};
+use advent_of_code::config;
+use advent_of_code::days::{self, Solution};
+use advent_of_code::{ANSI_BOLD, ANSI_RESET};
+use std::process;
+
+struct Args {
+    year: u16,
+    days: Option<Vec<u8>>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(config::default_year),
+        days: args.opt_free_from_fn(days::parse_selection)?,
+    })
+}
+
+fn verify(solution: &Solution) -> Vec<(u8, Verdict)> {
+    let answers = Answers::read(solution.year, solution.day).unwrap_or_default();
+    let input = advent_of_code::try_read_file("inputs", solution.year, solution.day);
+
+    solution
+        .parts()
+        .into_iter()
+        .map(|(part, solver)| {
+            let verdict = match &input {
+                Ok(input) => answers.verify(part, solver(input).as_deref()),
+                Err(e) => Verdict::InputError(e.to_string()),
+            };
+            (part, verdict)
+        })
+        .collect()
+}
+
+fn main() {
+    let solutions = match parse_args() {
+        Ok(args) => days::select(args.year, args.days.as_deref()),
+        Err(_) => {
+            eprintln!(
+                "Optionally specify days (as integers or ranges). example: `cargo verify 3-7,12`"
+            );
+            process::exit(1);
+        }
+    };
+
+    let mut failures = 0;
+    for solution in solutions {
+        for (part, verdict) in verify(solution) {
+            if verdict.is_failure() {
+                failures += 1;
+            }
+            println!(
+                "{}Day {:02} Part {}{}: {}",
+                ANSI_BOLD, solution.day, part, ANSI_RESET, verdict
+            );
+        }
+    }
+
+    if failures > 0 {
+        println!("---");
+        println!("{} part(s) did not produce the accepted answer.", failures);
+        process::exit(1);
+    }
+}
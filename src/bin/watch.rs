@@ -0,0 +1,184 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::{config, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::process::{self, Command, Stdio};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+struct Args {
+    day: u8,
+    year: u16,
+    interval: Duration,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or_else(config::default_year),
+        interval: args
+            .opt_value_from_str("--interval")?
+            .map_or(Duration::from_millis(500), Duration::from_secs_f64),
+        day: args.free_from_str()?,
+    })
+}
+
+/// Answers of one run, by part.
+type Answers = BTreeMap<u8, String>;
+
+/// Modification times of the watched files, `None` for files that do not exist (yet).
+fn modified(paths: &[PathBuf]) -> Vec<Option<SystemTime>> {
+    paths
+        .iter()
+        .map(|path| path.metadata().and_then(|m| m.modified()).ok())
+        .collect()
+}
+
+fn cargo(args: &[&str]) -> Command {
+    let mut cmd = Command::new(env!("CARGO"));
+    cmd.args(args).current_dir(advent_of_code::project_dir());
+    cmd
+}
+
+/// Runs the day on its real input and collects the answers from its JSON output.
+fn run_day(bin_name: &str) -> Option<Answers> {
+    let output = cargo(&["run", "--quiet", "--release", "--bin", bin_name, "--"])
+        .args(["--format", "json"])
+        .stderr(Stdio::inherit())
+        .output()
+        .ok()?;
+
+    let mut answers = Answers::new();
+    for line in String::from_utf8_lossy(&output.stdout).lines() {
+        match serde_json::from_str::<serde_json::Value>(line) {
+            Ok(record) => {
+                let part = record["part"].as_u64().unwrap_or_default() as u8;
+                let answer = match record["answer"].as_str() {
+                    Some(answer) => answer.to_string(),
+                    None => record["status"].as_str().unwrap_or_default().to_string(),
+                };
+                let elapsed = Duration::from_nanos(record["duration_ns"].as_u64().unwrap_or(0));
+                println!(
+                    "{}Part {}{}: {} {}(elapsed: {:.2?}){}",
+                    ANSI_BOLD, part, ANSI_RESET, answer, ANSI_ITALIC, elapsed, ANSI_RESET
+                );
+                answers.insert(part, answer);
+            }
+            // e.g. output of a `println!` left in the solution.
+            Err(_) => println!("{}", line),
+        }
+    }
+    output.status.success().then_some(answers)
+}
+
+/// Describes how the answers changed since the previous run.
+fn diff(previous: &Answers, current: &Answers) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, answer)| match previous.get(part) {
+            None => format!("Part {}: {} (new)", part, answer),
+            Some(old) if old == answer => format!("Part {}: unchanged", part),
+            Some(old) => format!("Part {}: {} -> {}", part, old, answer),
+        })
+        .collect()
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(_) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo watch-day 7`");
+            process::exit(1);
+        }
+    };
+
+    let bin_name = advent_of_code::bin_name(args.year, args.day);
+    let paths = vec![
+        advent_of_code::project_dir()
+            .join("src")
+            .join("bin")
+            .join(format!("{}.rs", bin_name)),
+        advent_of_code::project_dir().join("src").join("helpers.rs"),
+        advent_of_code::input_path("inputs", args.year, args.day),
+        advent_of_code::input_path("examples", args.year, args.day),
+    ];
+    if !paths[0].exists() {
+        eprintln!(
+            "\"{}\" does not exist. Try `cargo scaffold {}` first.",
+            paths[0].display(),
+            args.day
+        );
+        process::exit(1);
+    }
+
+    let mut last_modified = vec![];
+    let mut previous = Answers::new();
+    loop {
+        let current_modified = modified(&paths);
+        if current_modified == last_modified {
+            thread::sleep(args.interval);
+            continue;
+        }
+        last_modified = current_modified;
+
+        // clears the screen, like cargo-watch does.
+        print!("\x1b[2J\x1b[H");
+        println!(
+            "🎄 {}Watching day {:02}{} 🎄",
+            ANSI_BOLD, args.day, ANSI_RESET
+        );
+        for path in paths.iter() {
+            println!("{}{}{}", ANSI_ITALIC, path.display(), ANSI_RESET);
+        }
+
+        println!("---");
+        let tests = cargo(&["test", "--quiet", "--bin", &bin_name]).status();
+        if !tests.is_ok_and(|status| status.success()) {
+            println!("Tests against the examples failed.");
+        }
+
+        println!("---");
+        match run_day(&bin_name) {
+            Some(answers) => {
+                println!("---");
+                for line in diff(&previous, &answers) {
+                    println!("{}", line);
+                }
+                previous = answers;
+            }
+            None => println!("Running day {:02} failed.", args.day),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let answers = |parts: &[(u8, &str)]| {
+            parts
+                .iter()
+                .map(|(part, answer)| (*part, answer.to_string()))
+                .collect::<Answers>()
+        };
+
+        assert_eq!(
+            diff(&answers(&[]), &answers(&[(1, "24000")])),
+            vec!["Part 1: 24000 (new)"]
+        );
+        assert_eq!(
+            diff(
+                &answers(&[(1, "24000"), (2, "unsolved")]),
+                &answers(&[(1, "24000"), (2, "45000")])
+            ),
+            vec!["Part 1: unchanged", "Part 2: unsolved -> 45000"]
+        );
+    }
+}
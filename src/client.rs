@@ -0,0 +1,306 @@
+/*
+ * This file contains template code.
+ * Talks to adventofcode.com, or any server at a configured base URL.
+ */
+use std::env;
+use std::io::Read;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::config::Config;
+use crate::manifest;
+use crate::submit::{self, Outcome};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// As requested by the Advent of Code maintainers for automated tools.
+const USER_AGENT: &str = "github.com/ArcticXWolf/adventofcode2022 template";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/// The transport used by `Client`. Error statuses are responses, only failing to talk to the
+/// server at all is an error.
+pub trait Http {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String>;
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, String>;
+}
+
+pub struct UreqHttp {
+    agent: ureq::Agent,
+}
+
+impl Default for UreqHttp {
+    fn default() -> Self {
+        UreqHttp {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+}
+
+impl UreqHttp {
+    fn response(result: Result<ureq::Response, ureq::Error>) -> Result<Response, String> {
+        let response = match result {
+            Ok(response) => response,
+            Err(ureq::Error::Status(_, response)) => response,
+            Err(e) => return Err(e.to_string()),
+        };
+        let status = response.status();
+        let mut body = String::new();
+        response
+            .into_reader()
+            .read_to_string(&mut body)
+            .map_err(|e| e.to_string())?;
+        Ok(Response { status, body })
+    }
+}
+
+impl Http for UreqHttp {
+    fn get(&self, url: &str, session: &str) -> Result<Response, String> {
+        Self::response(
+            self.agent
+                .get(url)
+                .set("Cookie", &format!("session={}", session))
+                .call(),
+        )
+    }
+
+    fn post_form(
+        &self,
+        url: &str,
+        session: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Response, String> {
+        Self::response(
+            self.agent
+                .post(url)
+                .set("Cookie", &format!("session={}", session))
+                .send_form(form),
+        )
+    }
+}
+
+/// Puzzles unlock at midnight EST (05:00 UTC) on their day in December.
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    // days since 1970-01-01 of December 1st, see http://howardhinnant.github.io/date_algorithms.html
+    let (y, m, d) = (year as i64, 12, day as i64);
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m - 3) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+    UNIX_EPOCH + Duration::from_secs((days * 86400 + 5 * 3600) as u64)
+}
+
+/// The session cookie from `AOC_SESSION`, falling back to `aoc.toml`.
+pub fn session(config: &Config) -> Option<String> {
+    env::var(SESSION_VAR)
+        .ok()
+        .or_else(|| config.session.clone())
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+}
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    http: Box<dyn Http>,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, http: Box<dyn Http>) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            http,
+        }
+    }
+
+    /// Takes the session and base URL from the environment, falling back to `aoc.toml`.
+    pub fn from_config() -> Result<Self, String> {
+        let config = Config::load()?;
+        let session = session(&config).ok_or_else(|| {
+            format!(
+                "no session cookie, set {} or `session` in aoc.toml",
+                SESSION_VAR
+            )
+        })?;
+        let base_url = env::var(BASE_URL_VAR)
+            .ok()
+            .or(config.base_url)
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string());
+        Ok(Client::new(&base_url, &session, Box::<UreqHttp>::default()))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// See `manifest::account_id`.
+    pub fn account(&self) -> String {
+        manifest::account_id(&self.session)
+    }
+
+    pub fn day_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    pub fn get(&self, url: &str) -> Result<Response, String> {
+        self.http.get(url, &self.session)
+    }
+
+    pub fn post_form(&self, url: &str, form: &[(&str, &str)]) -> Result<Response, String> {
+        self.http.post_form(url, &self.session, form)
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, String> {
+        let response = self.get(&format!("{}/input", self.day_url(year, day)))?;
+        match response.status {
+            200 => Ok(response.body),
+            404 => Err(format!(
+                "the input of day {} of {} is not available (yet)",
+                day, year
+            )),
+            // the server answers invalid sessions with 400 or 500.
+            400 | 500 => Err(format!(
+                "server responded with status {}, is the session cookie still valid?",
+                response.status
+            )),
+            status => Err(format!("server responded with status {}", status)),
+        }
+    }
+
+    /// The puzzle page, which includes part two once part one is solved.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, String> {
+        let response = self.get(&self.day_url(year, day))?;
+        match response.status {
+            200 => Ok(response.body),
+            404 => Err(format!(
+                "the puzzle of day {} of {} is not available (yet)",
+                day, year
+            )),
+            status => Err(format!("server responded with status {}", status)),
+        }
+    }
+
+    pub fn submit(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<Outcome, String> {
+        let url = format!("{}/answer", self.day_url(year, day));
+        let level = part.to_string();
+        let response = self.post_form(&url, &[("level", &level), ("answer", answer)])?;
+        match response.status {
+            200 => Ok(submit::parse_response(&response.body)),
+            400 | 500 => Err(format!(
+                "server responded with status {}, is the session cookie still valid?",
+                response.status
+            )),
+            status => Err(format!("server responded with status {}", status)),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// A stand-in server that answers one request, returns its base URL and the raw request.
+    pub fn serve_once(status: u16, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let body = body.to_string();
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                request.push_str(&line);
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut content = vec![0; content_length];
+            reader.read_exact(&mut content).unwrap();
+            request.push_str(&String::from_utf8(content).unwrap());
+
+            write!(
+                reader.get_mut(),
+                "HTTP/1.1 {} Stand-in\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        let secs = |year, day| {
+            unlock_time(year, day)
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs()
+        };
+        assert_eq!(secs(2022, 1), 1669870800);
+        assert_eq!(secs(2015, 25), 1451019600);
+    }
+
+    fn client(base_url: &str) -> Client {
+        Client::new(base_url, "abc", Box::<UreqHttp>::default())
+    }
+
+    #[test]
+    fn test_input() {
+        let (base_url, server) = serve_once(200, "1000\n2000\n");
+        assert_eq!(client(&base_url).input(2022, 1).unwrap(), "1000\n2000\n");
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1\r\n"));
+        assert!(request.contains("Cookie: session=abc\r\n"));
+    }
+
+    #[test]
+    fn test_input_errors() {
+        let (base_url, server) = serve_once(404, "Not found");
+        let error = client(&base_url).input(2030, 1).unwrap_err();
+        assert_eq!(error, "the input of day 1 of 2030 is not available (yet)");
+        server.join().unwrap();
+
+        let (base_url, server) = serve_once(500, "");
+        let error = client(&base_url).input(2022, 1).unwrap_err();
+        assert!(error.contains("session cookie"));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_submit() {
+        let body = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+        let (base_url, server) = serve_once(200, body);
+        let outcome = client(&base_url).submit(2022, 1, 2, "45000").unwrap();
+        assert_eq!(outcome, Outcome::TooLow);
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=45000"));
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ * This file contains template code.
+ * Optional project settings, read from `aoc.toml` in the project root.
+ */
+use std::fs;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::DEFAULT_YEAR;
+
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Year used by the runner and the tools when no `--year` is given.
+    pub year: Option<u16>,
+    /// Session cookie for adventofcode.com. Prefer the `AOC_SESSION` environment variable, which
+    /// takes precedence, so the cookie does not end up in git.
+    pub session: Option<String>,
+    /// Server to download from, e.g. a local stand-in. Overridden by `AOC_BASE_URL`.
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        toml::from_str(contents).map_err(|e| e.to_string())
+    }
+
+    /// Reads `aoc.toml`, a missing file is the same as an empty one.
+    pub fn load() -> Result<Self, String> {
+        match fs::read_to_string(Self::path()) {
+            Ok(contents) => {
+                Self::parse(&contents).map_err(|e| format!("invalid {}: {}", CONFIG_FILE, e))
+            }
+            Err(_) => Ok(Config::default()),
+        }
+    }
+
+    pub fn path() -> PathBuf {
+        crate::project_dir().join(CONFIG_FILE)
+    }
+}
+
+/// The year to use when none is given on the command line.
+pub fn default_year() -> u16 {
+    match Config::load() {
+        Ok(config) => config.year.unwrap_or(DEFAULT_YEAR),
+        Err(e) => {
+            eprintln!("{}", e);
+            DEFAULT_YEAR
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+        assert_eq!(Config::parse("year = 2021").unwrap().year, Some(2021));
+        assert!(Config::parse("yaer = 2021").is_err());
+
+        let config = Config::parse("base_url = \"http://localhost:8080\"").unwrap();
+        assert_eq!(config.base_url.as_deref(), Some("http://localhost:8080"));
+        assert_eq!(config.session, None);
+    }
+}
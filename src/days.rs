@@ -0,0 +1,227 @@
+/*
+ * Registry of all solutions. Every day in `./bin/` is compiled into the library as well, so the
+ * all-days runner (and any other tool) can call the solvers in-process.
+ * Add new days both as a module here (behind `#[cfg(not(test))]`, like the others) and to
+ * `SOLUTIONS`, together with their year. Only the days' `main` is allowed to be dead code here,
+ * with `#[allow(dead_code)]` on the function itself.
+ */
+
+// the days' own tests run in their binaries, so the library's test build leaves the days out
+// instead of running every test twice.
+#[cfg(not(test))]
+use crate::params::Params;
+
+#[cfg(not(test))]
+#[path = "bin/01.rs"]
+mod day01;
+#[cfg(not(test))]
+#[path = "bin/02.rs"]
+mod day02;
+#[cfg(not(test))]
+#[path = "bin/03.rs"]
+mod day03;
+#[cfg(not(test))]
+#[path = "bin/04.rs"]
+mod day04;
+#[cfg(not(test))]
+#[path = "bin/05.rs"]
+mod day05;
+#[cfg(not(test))]
+#[path = "bin/06.rs"]
+mod day06;
+#[cfg(not(test))]
+#[path = "bin/07.rs"]
+mod day07;
+#[cfg(not(test))]
+#[path = "bin/08.rs"]
+mod day08;
+#[cfg(not(test))]
+#[path = "bin/09.rs"]
+mod day09;
+#[cfg(not(test))]
+#[path = "bin/10.rs"]
+mod day10;
+#[cfg(not(test))]
+#[path = "bin/11.rs"]
+mod day11;
+#[cfg(not(test))]
+#[path = "bin/12.rs"]
+mod day12;
+#[cfg(not(test))]
+#[path = "bin/13.rs"]
+mod day13;
+#[cfg(not(test))]
+#[path = "bin/14.rs"]
+mod day14;
+#[cfg(not(test))]
+#[path = "bin/15.rs"]
+mod day15;
+#[cfg(not(test))]
+#[path = "bin/16.rs"]
+mod day16;
+#[cfg(not(test))]
+#[path = "bin/17.rs"]
+mod day17;
+#[cfg(not(test))]
+#[path = "bin/18.rs"]
+mod day18;
+#[cfg(not(test))]
+#[path = "bin/19.rs"]
+mod day19;
+#[cfg(not(test))]
+#[path = "bin/20.rs"]
+mod day20;
+#[cfg(not(test))]
+#[path = "bin/21.rs"]
+mod day21;
+#[cfg(not(test))]
+#[path = "bin/22.rs"]
+mod day22;
+#[cfg(not(test))]
+#[path = "bin/23.rs"]
+mod day23;
+#[cfg(not(test))]
+#[path = "bin/24.rs"]
+mod day24;
+#[cfg(not(test))]
+#[path = "bin/25.rs"]
+mod day25;
+
+/// A solver for one part of a day. The answer is already formatted for display.
+pub type Solver = fn(&str) -> Option<String>;
+
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+}
+
+impl Solution {
+    pub fn parts(&self) -> [(u8, Solver); 2] {
+        [(1, self.part_one), (2, self.part_two)]
+    }
+}
+
+#[cfg(not(test))]
+macro_rules! solution {
+    ($year:expr, $day:expr, $module:ident) => {
+        Solution {
+            year: $year,
+            day: $day,
+            part_one: |input| $module::part_one(input).map(|r| r.to_string()),
+            part_two: |input| $module::part_two(input).map(|r| r.to_string()),
+        }
+    };
+    // the runner always uses the parameters for the real input.
+    ($year:expr, $day:expr, $module:ident, $params:ident) => {
+        Solution {
+            year: $year,
+            day: $day,
+            part_one: |input| {
+                $module::part_one(input, &<$module::$params as Params>::real())
+                    .map(|r| r.to_string())
+            },
+            part_two: |input| {
+                $module::part_two(input, &<$module::$params as Params>::real())
+                    .map(|r| r.to_string())
+            },
+        }
+    };
+}
+
+#[cfg(not(test))]
+pub const SOLUTIONS: &[Solution] = &[
+    solution!(2022, 1, day01),
+    solution!(2022, 2, day02),
+    solution!(2022, 3, day03),
+    solution!(2022, 4, day04),
+    solution!(2022, 5, day05),
+    solution!(2022, 6, day06),
+    solution!(2022, 7, day07),
+    solution!(2022, 8, day08),
+    solution!(2022, 9, day09),
+    solution!(2022, 10, day10),
+    solution!(2022, 11, day11),
+    solution!(2022, 12, day12),
+    solution!(2022, 13, day13),
+    solution!(2022, 14, day14),
+    solution!(2022, 15, day15, Params),
+    solution!(2022, 16, day16),
+    solution!(2022, 17, day17),
+    solution!(2022, 18, day18),
+    solution!(2022, 19, day19, Params),
+    solution!(2022, 20, day20),
+    solution!(2022, 21, day21),
+    solution!(2022, 22, day22),
+    solution!(2022, 23, day23),
+    solution!(2022, 24, day24),
+    solution!(2022, 25, day25),
+];
+#[cfg(test)]
+pub const SOLUTIONS: &[Solution] = &[];
+
+pub fn get(year: u16, day: u8) -> Option<&'static Solution> {
+    SOLUTIONS.iter().find(|s| s.year == year && s.day == day)
+}
+
+/// Parses a selection of days such as `3-7,12` into a sorted list without duplicates.
+pub fn parse_selection(spec: &str) -> Result<Vec<u8>, String> {
+    let parse_day = |day: &str| {
+        day.trim()
+            .parse::<u8>()
+            .map_err(|_| format!("invalid day \"{}\"", day))
+    };
+
+    let mut days = vec![];
+    for range in spec.split(',') {
+        let (start, end) = match range.split_once('-') {
+            Some((start, end)) => (parse_day(start)?, parse_day(end)?),
+            None => {
+                let day = parse_day(range)?;
+                (day, day)
+            }
+        };
+        if start > end {
+            return Err(format!("invalid range \"{}\"", range));
+        }
+        days.extend(start..=end);
+    }
+
+    days.sort_unstable();
+    days.dedup();
+    Ok(days)
+}
+
+/// Returns the registered solutions of a year for the selected days, or all days for `None`.
+pub fn select(year: u16, days: Option<&[u8]>) -> Vec<&'static Solution> {
+    select_from(SOLUTIONS, year, days)
+}
+
+fn select_from<'a>(solutions: &'a [Solution], year: u16, days: Option<&[u8]>) -> Vec<&'a Solution> {
+    solutions
+        .iter()
+        .filter(|s| s.year == year && days.is_none_or(|days| days.contains(&s.day)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select() {
+        let solution = |year, day| Solution {
+            year,
+            day,
+            part_one: |_| None,
+            part_two: |_| None,
+        };
+        let solutions = [solution(2022, 2), solution(2022, 24), solution(2021, 2)];
+
+        let selected = select_from(&solutions, 2022, Some(&[2, 24, 30]));
+        assert_eq!(selected.iter().map(|s| s.day).collect::<Vec<_>>(), [2, 24]);
+        assert_eq!(select_from(&solutions, 2022, None).len(), 2);
+        assert!(select_from(&solutions, 2015, None).is_empty());
+    }
+}
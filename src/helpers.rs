@@ -260,6 +260,183 @@ where
     }
 }
 
+/// A dense grid, stored row by row, with its top left corner at (0,0). Cheaper than `PointGrid`
+/// for puzzles where every point has a value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), width * height, "grid has the wrong size.");
+        Self {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses a grid of characters, one row per line. Panics if the rows differ in length.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Self {
+        let width = input.lines().next().map_or(0, |l| l.chars().count());
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in input.lines() {
+            assert_eq!(line.chars().count(), width, "rows differ in length.");
+            cells.extend(line.chars().map(&mut f));
+            height += 1;
+        }
+        Self::new(width, height, cells)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: &Point<isize>) -> bool {
+        self.index(point).is_some()
+    }
+
+    fn index(&self, point: &Point<isize>) -> Option<usize> {
+        let (x, y) = (
+            usize::try_from(point.x).ok()?,
+            usize::try_from(point.y).ok()?,
+        );
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+
+    pub fn get(&self, point: &Point<isize>) -> Option<&T> {
+        self.index(point).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, point: &Point<isize>) -> Option<&mut T> {
+        self.index(point).map(|i| &mut self.cells[i])
+    }
+
+    /// All points row by row, paired with their values.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<isize>, &T)> {
+        let width = self.width;
+        self.cells.iter().enumerate().map(move |(i, value)| {
+            let point = Point {
+                x: (i % width) as isize,
+                y: (i / width) as isize,
+            };
+            (point, value)
+        })
+    }
+
+    fn neighbours_in<'a>(
+        &'a self,
+        point: Point<isize>,
+        directions: Iter<'static, PointDirection>,
+    ) -> impl Iterator<Item = (Point<isize>, &'a T)> {
+        directions.filter_map(move |direction| {
+            let neighbour = point.get_point_in_direction(direction, 1);
+            self.get(&neighbour).map(|value| (neighbour, value))
+        })
+    }
+
+    /// The up to 4 horizontal and vertical neighbours inside the grid.
+    pub fn neighbours(&self, point: Point<isize>) -> impl Iterator<Item = (Point<isize>, &T)> {
+        self.neighbours_in(point, PointDirection::all())
+    }
+
+    /// The up to 8 neighbours inside the grid, including diagonal ones.
+    pub fn neighbours_with_diagonals(
+        &self,
+        point: Point<isize>,
+    ) -> impl Iterator<Item = (Point<isize>, &T)> {
+        self.neighbours_in(point, PointDirection::all_with_diagonals())
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column out of bounds.");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+}
+
+impl<T> Display for Grid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for value in row {
+                write!(f, "{}", value)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> From<Grid<T>> for PointGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        let width = grid.width;
+        let points = grid
+            .cells
+            .into_iter()
+            .enumerate()
+            .map(|(i, value)| {
+                let point = Point {
+                    x: (i % width) as isize,
+                    y: (i / width) as isize,
+                };
+                (point, value)
+            })
+            .collect();
+        PointGrid { points }
+    }
+}
+
+/// Shifts the points so the top left corner of the `PointGrid` ends up at (0,0). Points without
+/// a value get `T::default()`.
+impl<T: Default> From<PointGrid<T>> for Grid<T> {
+    fn from(mut grid: PointGrid<T>) -> Self {
+        if grid.points.is_empty() {
+            return Grid::new(0, 0, vec![]);
+        }
+        let (min, max) = grid.dimensions();
+        let (width, height) = ((max.x - min.x + 1) as usize, (max.y - min.y + 1) as usize);
+        let cells = (0..width * height)
+            .map(|i| {
+                let point = Point {
+                    x: min.x + (i % width) as isize,
+                    y: min.y + (i / width) as isize,
+                };
+                grid.points.remove(&point).unwrap_or_default()
+            })
+            .collect();
+        Grid::new(width, height, cells)
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub struct Point3<T> {
     pub x: T,
@@ -325,3 +502,58 @@ pub fn gcd(first: usize, second: usize) -> usize {
         min = res;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: isize, y: isize) -> Point<isize> {
+        Point { x, y }
+    }
+
+    #[test]
+    fn test_grid() {
+        let mut grid = Grid::parse("123\n456\n", |c| c.to_digit(10).unwrap());
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(&point(2, 1)), Some(&6));
+        assert_eq!(grid.get(&point(3, 0)), None);
+        assert_eq!(grid.get(&point(-1, 0)), None);
+
+        *grid.get_mut(&point(0, 0)).unwrap() = 7;
+        assert_eq!(grid.row(0), &[7, 2, 3]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.to_string(), "723\n456\n");
+
+        let neighbours = |points: Vec<(Point<isize>, &u32)>| {
+            points.into_iter().map(|(_, v)| *v).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            neighbours(grid.neighbours(point(0, 0)).collect()),
+            vec![2, 4]
+        );
+        assert_eq!(
+            neighbours(grid.neighbours_with_diagonals(point(1, 0)).collect()),
+            vec![3, 6, 5, 4, 7]
+        );
+    }
+
+    #[test]
+    fn test_grid_point_grid_conversion() {
+        let grid = Grid::parse("#.\n.#\n", |c| c == '#');
+        let point_grid = PointGrid::from(grid.clone());
+        assert_eq!(point_grid.points.len(), 4);
+        assert_eq!(point_grid.get(&point(1, 1)), Some(&true));
+        assert_eq!(Grid::from(point_grid), grid);
+
+        let mut point_grid = PointGrid::default();
+        point_grid.insert(point(-1, 5), 1);
+        point_grid.insert(point(1, 6), 2);
+        let grid = Grid::from(point_grid);
+        assert_eq!(
+            grid.rows().collect::<Vec<_>>(),
+            vec![&[1, 0, 0], &[0, 0, 2]]
+        );
+        assert_eq!(Grid::<u8>::from(PointGrid::default()).width(), 0);
+    }
+}
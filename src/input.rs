@@ -0,0 +1,394 @@
+/*
+ * This file contains template code.
+ * Fallible loading of inputs and examples.
+ */
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+
+use crate::config;
+
+/// Reads inputs from this directory instead of `src/<year>/inputs`.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Shown instead of a path for inputs passed with `--input -`.
+const STDIN_PATH: &str = "<stdin>";
+
+#[derive(Debug)]
+pub enum InputError {
+    /// `download` is the year and day to fetch, if the file can be downloaded.
+    Missing {
+        path: PathBuf,
+        download: Option<(u16, u8)>,
+    },
+    Empty {
+        path: PathBuf,
+        download: Option<(u16, u8)>,
+    },
+    NotUtf8 {
+        path: PathBuf,
+    },
+    CrlfLineEndings {
+        path: PathBuf,
+    },
+    Io {
+        path: PathBuf,
+        error: io::Error,
+    },
+}
+
+impl InputError {
+    pub fn path(&self) -> &Path {
+        match self {
+            InputError::Missing { path, .. }
+            | InputError::Empty { path, .. }
+            | InputError::NotUtf8 { path }
+            | InputError::CrlfLineEndings { path }
+            | InputError::Io { path, .. } => path,
+        }
+    }
+
+    /// Whether there is no usable input yet, as opposed to a broken one.
+    pub fn is_missing(&self) -> bool {
+        matches!(self, InputError::Missing { .. } | InputError::Empty { .. })
+    }
+
+    fn hint(&self) -> Option<String> {
+        match self {
+            InputError::Missing { download, .. } | InputError::Empty { download, .. } => {
+                download.map(|(year, day)| download_command(year, day))
+            }
+            InputError::CrlfLineEndings { .. } => {
+                Some("convert the file to LF line endings".to_string())
+            }
+            _ => None,
+        }
+    }
+
+    fn with_download(mut self, year: u16, day: u8) -> Self {
+        if let InputError::Missing { download, .. } | InputError::Empty { download, .. } = &mut self
+        {
+            *download = Some((year, day));
+        }
+        self
+    }
+}
+
+/// `cargo download` uses the year of `aoc.toml` without `--year`, so the hint does too.
+fn download_command(year: u16, day: u8) -> String {
+    if year == config::default_year() {
+        format!("run `cargo download {}`", day)
+    } else {
+        format!("run `cargo download {} --year {}`", day, year)
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let path = self.path().display();
+        match self {
+            InputError::Missing { .. } => write!(f, "input file \"{}\" does not exist", path)?,
+            InputError::Empty { .. } => write!(f, "input file \"{}\" is empty", path)?,
+            InputError::NotUtf8 { .. } => write!(f, "input file \"{}\" is not valid UTF-8", path)?,
+            InputError::CrlfLineEndings { .. } => {
+                write!(f, "input file \"{}\" has CRLF line endings", path)?
+            }
+            InputError::Io { error, .. } => {
+                write!(f, "could not read input file \"{}\": {}", path, error)?
+            }
+        }
+        match self.hint() {
+            Some(hint) => write!(f, " - {}.", hint),
+            None => write!(f, "."),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+pub fn read_path(path: PathBuf) -> Result<String, InputError> {
+    match fs::read(&path) {
+        Ok(bytes) => check_contents(path, bytes),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(InputError::Missing {
+            path,
+            download: None,
+        }),
+        Err(error) => Err(InputError::Io { path, error }),
+    }
+}
+
+fn check_contents(path: PathBuf, bytes: Vec<u8>) -> Result<String, InputError> {
+    let contents = match String::from_utf8(bytes) {
+        Ok(contents) => contents,
+        Err(_) => return Err(InputError::NotUtf8 { path }),
+    };
+
+    if contents.trim().is_empty() {
+        Err(InputError::Empty {
+            path,
+            download: None,
+        })
+    } else if contents.contains("\r\n") {
+        Err(InputError::CrlfLineEndings { path })
+    } else {
+        Ok(contents)
+    }
+}
+
+pub fn try_read_file(folder: &str, year: u16, day: u8) -> Result<String, InputError> {
+    let path = crate::input_path(folder, year, day);
+    if folder != "inputs" {
+        return allow_empty(read_path(path));
+    }
+
+    match embedded_input(year, day) {
+        Some(contents) => check_contents(path, contents.as_bytes().to_vec()),
+        None => read_path(path).map_err(|e| e.with_download(year, day)),
+    }
+}
+
+#[cfg(feature = "embed-inputs")]
+mod embedded {
+    include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+}
+
+/// The input baked in by the `embed-inputs` feature. Setting `AOC_INPUT_DIR` bypasses it.
+pub fn embedded_input(year: u16, day: u8) -> Option<&'static str> {
+    #[cfg(feature = "embed-inputs")]
+    if std::env::var_os(INPUT_DIR_VAR).is_none() {
+        return embedded::INPUTS
+            .iter()
+            .find(|(y, d, _)| (*y, *d) == (year, day))
+            .map(|(_, _, contents)| *contents);
+    }
+    let _ = (year, day);
+    None
+}
+
+pub fn try_read_file_extended(
+    folder: &str,
+    year: u16,
+    day: u8,
+    suffix: u8,
+) -> Result<String, InputError> {
+    let path = crate::year_dir(folder, year).join(format!("{:02}_{:02}.txt", day, suffix));
+    match folder {
+        "inputs" => read_path(path),
+        _ => allow_empty(read_path(path)),
+    }
+}
+
+/// Only an empty input is an error. Empty examples are the stubs `cargo scaffold` creates, and
+/// the parts are expected to return `None` for them.
+fn allow_empty(result: Result<String, InputError>) -> Result<String, InputError> {
+    match result {
+        Err(InputError::Empty { .. }) => Ok(String::new()),
+        result => result,
+    }
+}
+
+/// Writes `contents` to a temporary file next to `path` and renames it, so readers never see a
+/// partially written file. Temporary files are unique per process and call, so concurrent
+/// writers never share one.
+pub fn write_atomic(path: &Path, contents: &str) -> io::Result<()> {
+    static WRITES: AtomicUsize = AtomicUsize::new(0);
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let tmp_path = path.with_file_name(format!(
+        ".{}.{}.{}.tmp",
+        file_name,
+        std::process::id(),
+        WRITES.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::write(&tmp_path, contents)?;
+    fs::rename(&tmp_path, path).inspect_err(|_| {
+        fs::remove_file(&tmp_path).ok();
+    })
+}
+
+/// Takes an exclusive lock for writing `path`, released when the returned file is dropped. The
+/// lock is held on `.<name>.lock` next to `path`, as `write_atomic` replaces `path` itself.
+/// Blocks while another process holds the lock.
+pub fn lock(path: &Path) -> io::Result<fs::File> {
+    let file_name = path.file_name().unwrap_or_default().to_string_lossy();
+    let file = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path.with_file_name(format!(".{}.lock", file_name)))?;
+    file.lock()?;
+    Ok(file)
+}
+
+/// Whether `path` holds something, as opposed to not existing or the empty file of `scaffold`.
+pub fn has_contents(path: &Path) -> bool {
+    fs::read(path).is_ok_and(|contents| !contents.trim_ascii().is_empty())
+}
+
+/// Reads the input given with `--input <path>`, or from stdin for `--input -`.
+pub fn read_input_arg(arg: &Path) -> Result<String, InputError> {
+    if arg != Path::new("-") {
+        return read_path(arg.to_path_buf());
+    }
+
+    let path = PathBuf::from(STDIN_PATH);
+    let mut bytes = vec![];
+    match io::stdin().read_to_end(&mut bytes) {
+        Ok(_) => check_contents(path, bytes),
+        Err(error) => Err(InputError::Io { path, error }),
+    }
+}
+
+/// The input passed to a day binary with `--input`, or its example for `--example`. It replaces
+/// the one `solve!` was given and is only read once, so both parts see the same contents even
+/// when it comes from stdin.
+pub fn input_override(year: u16, day: u8) -> Option<&'static Result<String, InputError>> {
+    static INPUT: OnceLock<Option<Result<String, InputError>>> = OnceLock::new();
+    INPUT
+        .get_or_init(|| {
+            let mut args = pico_args::Arguments::from_env();
+            let example = args.contains("--example");
+            match args.opt_value_from_os_str("--input", |s| {
+                Ok::<_, std::convert::Infallible>(PathBuf::from(s))
+            }) {
+                Ok(Some(path)) => Some(read_input_arg(&path)),
+                Ok(None) if example => Some(try_read_file("examples", year, day)),
+                Ok(None) => None,
+                Err(e) => {
+                    eprintln!("Failed to process arguments: {}", e);
+                    std::process::exit(1);
+                }
+            }
+        })
+        .as_ref()
+}
+
+/// Anything `solve!` accepts as input: the contents itself, or the result of loading it.
+pub trait Input {
+    fn get(&self) -> Result<&str, &InputError>;
+}
+
+impl Input for str {
+    fn get(&self) -> Result<&str, &InputError> {
+        Ok(self)
+    }
+}
+
+impl Input for String {
+    fn get(&self) -> Result<&str, &InputError> {
+        Ok(self)
+    }
+}
+
+impl Input for Result<String, InputError> {
+    fn get(&self) -> Result<&str, &InputError> {
+        self.as_deref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn write_temp(name: &str, contents: &[u8]) -> PathBuf {
+        let dir = env::temp_dir()
+            .join("advent_of_code_input_tests")
+            .join("inputs");
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(name);
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_read_path_errors() {
+        let path = write_temp("ok.txt", b"1\n2\n");
+        assert_eq!(read_path(path).unwrap(), "1\n2\n");
+
+        let path = write_temp("empty.txt", b"\n");
+        let error = read_path(path).unwrap_err();
+        assert!(matches!(error, InputError::Empty { .. }));
+        assert!(error.to_string().ends_with("is empty."));
+        let error = error.with_download(2022, 7);
+        assert!(error.to_string().ends_with("run `cargo download 7`."));
+        // empty examples are fine.
+        assert_eq!(allow_empty(Err(error)).unwrap(), "");
+
+        let path = write_temp("crlf.txt", b"1\r\n2\r\n");
+        assert!(matches!(
+            read_path(path),
+            Err(InputError::CrlfLineEndings { .. })
+        ));
+
+        let path = write_temp("binary.txt", &[0xff, 0xfe, 0x00]);
+        assert!(matches!(read_path(path), Err(InputError::NotUtf8 { .. })));
+
+        let path = write_temp("ok.txt", b"").with_file_name("missing.txt");
+        let error = read_path(path).unwrap_err().with_download(2021, 3);
+        assert!(error.is_missing());
+        assert!(error
+            .to_string()
+            .ends_with("run `cargo download 3 --year 2021`."));
+    }
+
+    #[test]
+    fn test_input_dir_override() {
+        let dir = write_temp("05.txt", b"move 1 from 2 to 1\n")
+            .parent()
+            .unwrap()
+            .to_path_buf();
+        let path = crate::input_dir_with("inputs", 2022, Some(dir.clone().into())).join("05.txt");
+        let examples = crate::input_dir_with("examples", 2022, Some(dir.clone().into()));
+
+        assert_eq!(path, dir.join("05.txt"));
+        assert!(examples.starts_with(crate::project_dir()));
+        assert_eq!(read_input_arg(&path).unwrap(), "move 1 from 2 to 1\n");
+    }
+
+    #[test]
+    fn test_write_atomic() {
+        let path = write_temp("atomic.txt", b"old\n");
+        assert!(has_contents(&path));
+        write_atomic(&path, "new\n").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new\n");
+
+        write_atomic(&path, " \n").unwrap();
+        assert!(!has_contents(&path));
+        assert!(!has_contents(&path.with_file_name("nothing.txt")));
+    }
+
+    #[test]
+    fn test_concurrent_writes() {
+        let path = write_temp("concurrent.txt", b"");
+        std::thread::scope(|scope| {
+            for i in 0..8 {
+                let path = &path;
+                scope.spawn(move || {
+                    let _lock = lock(path).unwrap();
+                    write_atomic(path, &format!("{}\n", i)).unwrap();
+                });
+            }
+        });
+
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.trim().parse::<u8>().is_ok_and(|i| i < 8));
+        let leftovers = fs::read_dir(path.parent().unwrap())
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .filter(|name| name.starts_with(".concurrent.txt.") && name.ends_with(".tmp"))
+            .count();
+        assert_eq!(leftovers, 0);
+    }
+
+    #[cfg(feature = "embed-inputs")]
+    #[test]
+    fn test_embedded_inputs() {
+        for (year, day, contents) in embedded::INPUTS {
+            let path = crate::year_dir("inputs", *year).join(format!("{:02}.txt", day));
+            assert_eq!(fs::read_to_string(path).unwrap(), *contents);
+        }
+    }
+}
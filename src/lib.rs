@@ -4,103 +4,129 @@
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
 use std::env;
-use std::fs;
+use std::ffi::OsString;
+use std::path::PathBuf;
 
+// lets the day modules in `days` refer to the library as `advent_of_code`, like the binaries do.
+extern crate self as advent_of_code;
+
+pub mod answers;
+pub mod baseline;
+pub mod client;
+pub mod config;
+pub mod days;
 pub mod helpers;
+pub mod input;
+pub mod manifest;
+pub mod memory;
+pub mod ocr;
+pub mod output;
+pub mod params;
+pub mod puzzle;
+pub mod readme;
+pub mod runner;
+pub mod submit;
+
+pub use input::{try_read_file, try_read_file_extended};
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Year of the solutions in `./bin/NN.rs`. Solutions for other years are named `YYYY_NN.rs`.
+/// This is also the default year if `aoc.toml` does not set one.
+pub const DEFAULT_YEAR: u16 = 2022;
+
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
+    // parts that take the `params!` of their day as well.
+    ($part:expr, $solver:ident, $input:expr, $params:ty) => {{
+        let params = <$params as $crate::params::Params>::from_env();
+        $crate::solve!($part, |input| $solver(input, &params), $input)
+    }};
+    ($part:expr, $solver:expr, $input:expr) => {{
+        let (year, day) = option_env!("CARGO_BIN_NAME")
+            .and_then($crate::parse_bin_name)
+            .unwrap_or(($crate::DEFAULT_YEAR, 0));
+        let options = $crate::runner::Options::from_env();
+        if $part == options.part.unwrap_or(1) {
+            options.format.print_header();
         }
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+        if options.runs_part($part) {
+            let input = match $crate::input::input_override(year, day) {
+                Some(input) => input.as_deref(),
+                None => $crate::input::Input::get($input),
+            };
+            let input = match input {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("{}", e);
+                    std::process::exit(1);
+                }
+            };
+            let record = $crate::runner::run_part(day, $part, $solver, input, &options);
+            options.format.print_record(&record);
+        }
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+/// Name of the binary that holds the solution for a day, see `DEFAULT_YEAR`.
+pub fn bin_name(year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("{:02}", day)
+    } else {
+        format!("{}_{:02}", year, day)
+    }
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Inverse of `bin_name`, returns the year and day of a solution binary.
+pub fn parse_bin_name(name: &str) -> Option<(u16, u8)> {
+    match name.split_once('_') {
+        Some((year, day)) => Some((year.parse().ok()?, day.parse().ok()?)),
+        None => Some((DEFAULT_YEAR, name.parse().ok()?)),
+    }
 }
 
-pub fn read_file_extended(folder: &str, day: u8, suffix: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Root of the project, independent of the working directory the binaries are started from.
+pub fn project_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+}
 
-    let filepath = cwd
+/// Directory holding the inputs, examples, ... of a year, e.g. `src/2022/inputs`.
+pub fn year_dir(folder: &str, year: u16) -> PathBuf {
+    project_dir()
         .join("src")
+        .join(year.to_string())
         .join(folder)
-        .join(format!("{:02}_{:02}.txt", day, suffix));
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Directory of the files in `folder`. Inputs are read from `AOC_INPUT_DIR` instead, if it is set.
+pub fn input_dir(folder: &str, year: u16) -> PathBuf {
+    input_dir_with(folder, year, env::var_os(input::INPUT_DIR_VAR))
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// `input_dir` with the value of `AOC_INPUT_DIR` passed in.
+pub(crate) fn input_dir_with(folder: &str, year: u16, override_dir: Option<OsString>) -> PathBuf {
+    match override_dir {
+        Some(dir) if folder == "inputs" => PathBuf::from(dir),
+        _ => year_dir(folder, year),
+    }
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
-        }
-    })
+/// Path of a day's file in `folder`, see `input_dir`.
+pub fn input_path(folder: &str, year: u16, day: u8) -> PathBuf {
+    input_dir(folder, year).join(format!("{:02}.txt", day))
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+/// Like `input::try_read_file`, but panics if the file cannot be used.
+pub fn read_file(folder: &str, year: u16, day: u8) -> String {
+    input::try_read_file(folder, year, day).unwrap_or_else(|e| panic!("{}", e))
+}
+
+/// Like `input::try_read_file_extended`, but panics if the file cannot be used.
+pub fn read_file_extended(folder: &str, year: u16, day: u8, suffix: u8) -> String {
+    input::try_read_file_extended(folder, year, day, suffix).unwrap_or_else(|e| panic!("{}", e))
 }
 
 #[cfg(test)]
@@ -108,30 +134,19 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+    fn test_day_selection() {
+        assert_eq!(days::parse_selection("3-7,12"), Ok(vec![3, 4, 5, 6, 7, 12]));
+        assert_eq!(days::parse_selection("5, 1-2,2"), Ok(vec![1, 2, 5]));
+        assert!(days::parse_selection("7-3").is_err());
+        assert!(days::parse_selection("a").is_err());
+    }
+
+    #[test]
+    fn test_bin_names() {
+        assert_eq!(bin_name(2022, 7), "07");
+        assert_eq!(bin_name(2021, 7), "2021_07");
+        assert_eq!(parse_bin_name("07"), Some((2022, 7)));
+        assert_eq!(parse_bin_name("2021_25"), Some((2021, 25)));
+        assert_eq!(parse_bin_name("scaffold"), None);
     }
 }
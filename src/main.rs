@@ -2,45 +2,261 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::baseline::{self, Baseline};
+use advent_of_code::config;
+use advent_of_code::days;
+use advent_of_code::input;
+use advent_of_code::output::{Format, Record, Status};
+use advent_of_code::runner::{self, Options};
 use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use itertools::Itertools;
+use std::path::PathBuf;
+use std::process;
+use std::time::Duration;
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+struct Args {
+    year: u16,
+    options: Options,
+    baseline: PathBuf,
+    save_baseline: bool,
+    compare: bool,
+    threshold: f64,
+    jobs: usize,
+    only_solved: bool,
+    slowest: Option<usize>,
+    days: Option<Vec<u8>>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let mut options = Options::from_args(&mut args)?;
+    options.timeout = args
+        .opt_value_from_str("--timeout")?
+        .map(Duration::from_secs_f64);
+    let year = args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or_else(config::default_year);
+    Ok(Args {
+        year,
+        options,
+        baseline: args
+            .opt_value_from_str("--baseline")?
+            .unwrap_or_else(|| baseline::default_path(year)),
+        save_baseline: args.contains("--save-baseline"),
+        compare: args.contains("--compare"),
+        threshold: args.opt_value_from_str("--threshold")?.unwrap_or(10_f64),
+        jobs: args.opt_value_from_str(["-j", "--jobs"])?.unwrap_or(1),
+        only_solved: args.contains("--only-solved"),
+        slowest: args.opt_value_from_str("--slowest")?,
+        days: args.opt_free_from_fn(days::parse_selection)?,
+    })
+}
 
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    };
+    let options = args.options;
+    let format = options.format;
+    format.print_header();
 
+    // days without an input have not been solved yet, scaffolded days only have an empty one.
+    let solutions = days::select(args.year, args.days.as_deref())
+        .into_iter()
+        .filter(|s| {
+            !args.only_solved
+                || input::embedded_input(s.year, s.day).is_some()
+                || input::has_contents(&advent_of_code::input_path("inputs", s.year, s.day))
+        })
+        .collect::<Vec<_>>();
+    let mut records: Vec<Record> = vec![];
+    runner::run_days(&solutions, &options, args.jobs, |solution, day_records| {
+        if format == Format::Text {
             println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
+            println!("{}| Day {:02} |{}", ANSI_BOLD, solution.day, ANSI_RESET);
             println!("----------");
+        }
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+        if format == Format::Text && day_records.iter().all(|r| r.status == Status::MissingInput) {
+            // both parts share the input, so its error is the same for both.
+            match day_records.first().and_then(|r| r.error.as_ref()) {
+                Some(error) => println!("Not solved, {}", error),
+                None => println!("Not solved."),
+            }
+        } else {
+            for record in day_records.iter() {
+                format.print_record(record);
+            }
+        }
+        records.extend(day_records);
+    });
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+    if format == Format::Text {
+        match args.slowest {
+            Some(count) => print_slowest(&records, count),
+            None => print_summary(&records),
+        }
+        print_total(&records);
+    }
+
+    let mut regressed = false;
+    if args.compare {
+        match Baseline::load(&args.baseline) {
+            Ok(saved) => {
+                if let Err(e) = saved.check_mode(options.bench.is_some()) {
+                    eprintln!(
+                        "Failed to compare with \"{}\": {}",
+                        args.baseline.display(),
+                        e
+                    );
+                    process::exit(1);
                 }
+                let comparisons = saved.compare(&records);
+                regressed = baseline::print_comparisons(&comparisons, args.threshold);
+            }
+            Err(e) => {
+                eprintln!(
+                    "Failed to read baseline \"{}\": {}",
+                    args.baseline.display(),
+                    e
+                );
+                process::exit(1);
+            }
+        }
+    }
+
+    if args.save_baseline {
+        if let Err(e) =
+            Baseline::from_records(&records, options.bench.is_some()).save(&args.baseline)
+        {
+            eprintln!(
+                "Failed to write baseline \"{}\": {}",
+                args.baseline.display(),
+                e
             );
+            process::exit(1);
+        }
+        eprintln!("🎄 Saved baseline to \"{}\".", args.baseline.display());
+    }
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
-            }
-        })
-        .sum();
+    if regressed {
+        eprintln!(
+            "At least one part got more than {}% slower than the baseline.",
+            args.threshold
+        );
+        process::exit(1);
+    }
+}
+
+fn summary_cell(record: &Record) -> String {
+    match record.status {
+        Status::Solved => format!("{:.2?}", record.elapsed),
+        Status::Unsolved => "not solved".to_string(),
+        Status::MissingInput => "missing input".to_string(),
+        Status::InvalidInput => "invalid input".to_string(),
+        Status::Timeout => "timeout".to_string(),
+        Status::Panicked => "panicked".to_string(),
+    }
+}
+
+fn print_slowest(records: &[Record], count: usize) {
+    println!("----------");
+    println!("{}| Slowest parts |{}", ANSI_BOLD, ANSI_RESET);
+    println!("----------");
+    println!("| Day | Part | Time |");
+    println!("| :---: | :---: | :---: |");
+    for record in records
+        .iter()
+        .filter(|r| r.status == Status::Solved)
+        .sorted_by_key(|r| std::cmp::Reverse(r.elapsed))
+        .take(count)
+    {
+        println!(
+            "| {:02} | {} | {} |",
+            record.day,
+            record.part,
+            summary_cell(record)
+        );
+    }
+}
+
+fn print_summary(records: &[Record]) {
+    println!("----------");
+    println!("{}| Summary |{}", ANSI_BOLD, ANSI_RESET);
+    println!("----------");
+    println!("| Day | Part 1 | Part 2 |");
+    println!("| :---: | :---: | :---: |");
+    for (day, day_records) in &records.iter().group_by(|r| r.day) {
+        let day_records = day_records.collect::<Vec<_>>();
+        let cells = (1..=2)
+            .map(|part| match day_records.iter().find(|r| r.part == part) {
+                Some(record) => summary_cell(record),
+                None => "-".to_string(),
+            })
+            .join(" | ");
+        println!("| {:02} | {} |", day, cells);
+    }
 
+    let count = |status| records.iter().filter(|r| r.status == status).count();
     println!(
+        "{} solved, {} not solved, {} timed out, {} panicked, {} without input, {} with invalid input",
+        count(Status::Solved),
+        count(Status::Unsolved),
+        count(Status::Timeout),
+        count(Status::Panicked),
+        count(Status::MissingInput),
+        count(Status::InvalidInput)
+    );
+}
+
+fn print_total(records: &[Record]) {
+    let total: Duration = records
+        .iter()
+        .filter(|r| r.status == Status::Solved)
+        .map(|r| r.elapsed)
+        .sum();
+    print!(
         "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        ANSI_BOLD,
+        ANSI_RESET,
+        ANSI_ITALIC,
+        total.as_secs_f64() * 1000_f64,
+        ANSI_RESET
     );
+
+    // parts are independent, so their means add up and so do their variances.
+    let stats = records.iter().filter_map(|r| r.stats).collect::<Vec<_>>();
+    if !stats.is_empty() {
+        let mean: f64 = stats.iter().map(|s| s.mean.as_secs_f64()).sum();
+        let variance: f64 = stats.iter().map(|s| s.stddev.as_secs_f64().powi(2)).sum();
+        print!(
+            " {}(sum of medians, mean: {:.2}ms ± {:.2}ms){}",
+            ANSI_ITALIC,
+            mean * 1000_f64,
+            variance.sqrt() * 1000_f64,
+            ANSI_RESET
+        );
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the library's own tests are built without the days, see `days.rs`.
+    #[test]
+    fn test_solution_registry() {
+        assert_eq!(days::SOLUTIONS.len(), 25);
+        assert_eq!(days::select(2022, None).len(), 25);
+
+        let solution = days::get(2022, 1).unwrap();
+        let input = advent_of_code::read_file("examples", 2022, 1);
+        assert_eq!((solution.part_one)(&input), Some("24000".to_string()));
+        assert_eq!((solution.part_two)(&input), Some("45000".to_string()));
+    }
 }
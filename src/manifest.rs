@@ -0,0 +1,212 @@
+/*
+ * This file contains template code.
+ * Records which input was downloaded when and for which account, in `manifest.json` next to the
+ * inputs, so changed or foreign inputs can be told apart from the downloaded ones.
+ */
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::input;
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub sha256: String,
+    /// Unix timestamp in seconds.
+    pub downloaded_at: u64,
+    /// See `account_id`, `None` if the input was not downloaded with a session.
+    pub account: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub inputs: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputStatus {
+    /// Unchanged since it was downloaded by the current account.
+    Ok,
+    Missing,
+    /// Usually the empty file created by `cargo scaffold`.
+    Empty,
+    /// Not downloaded with `cargo download`, e.g. copied in by hand.
+    Untracked,
+    /// Changed since it was downloaded.
+    Modified,
+    /// Downloaded by a different account than the current session.
+    OtherAccount,
+}
+
+impl InputStatus {
+    pub fn description(&self) -> &'static str {
+        match self {
+            InputStatus::Ok => "ok",
+            InputStatus::Missing => "missing",
+            InputStatus::Empty => "empty",
+            InputStatus::Untracked => "untracked",
+            InputStatus::Modified => "modified since download",
+            InputStatus::OtherAccount => "downloaded by another account",
+        }
+    }
+}
+
+pub fn sha256_hex(contents: &[u8]) -> String {
+    Sha256::digest(contents)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// Identifies the account of a session cookie without storing the cookie itself.
+pub fn account_id(session: &str) -> String {
+    sha256_hex(session.trim().as_bytes())[..16].to_string()
+}
+
+/// `timestamp` in seconds as `YYYY-MM-DD HH:MM UTC`.
+pub fn format_utc(timestamp: u64) -> String {
+    // civil date of the days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+    let z = (timestamp / 86400) as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = doy - (153 * mp + 2) / 5 + 1;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 };
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    let seconds = timestamp % 86400;
+    format!(
+        "{}-{:02}-{:02} {:02}:{:02} UTC",
+        y,
+        m,
+        d,
+        seconds / 3600,
+        seconds % 3600 / 60
+    )
+}
+
+impl Manifest {
+    /// Lives next to the inputs, so it follows `AOC_INPUT_DIR`.
+    pub fn path(year: u16) -> PathBuf {
+        crate::input_dir("inputs", year).join(MANIFEST_FILE)
+    }
+
+    /// A missing manifest is the same as an empty one.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).map_err(|e| e.to_string()),
+            Err(_) => Ok(Manifest::default()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let contents = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        input::write_atomic(path, &(contents + "\n")).map_err(|e| e.to_string())
+    }
+
+    pub fn get(&self, year: u16, day: u8) -> Option<&Entry> {
+        self.inputs.iter().find(|e| e.year == year && e.day == day)
+    }
+
+    /// Records a download, replacing an earlier one of the same day.
+    pub fn record(&mut self, year: u16, day: u8, contents: &str, account: Option<String>) {
+        let downloaded_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        self.inputs.retain(|e| !(e.year == year && e.day == day));
+        self.inputs.push(Entry {
+            year,
+            day,
+            sha256: sha256_hex(contents.as_bytes()),
+            downloaded_at,
+            account,
+        });
+        self.inputs.sort_by_key(|e| (e.year, e.day));
+    }
+
+    /// Status of an input given its contents, `None` if the file does not exist. The account is
+    /// only compared if there is a current one.
+    pub fn status(
+        &self,
+        year: u16,
+        day: u8,
+        contents: Option<&[u8]>,
+        account: Option<&str>,
+    ) -> InputStatus {
+        let contents = match contents {
+            None => return InputStatus::Missing,
+            Some(contents) if contents.trim_ascii().is_empty() => return InputStatus::Empty,
+            Some(contents) => contents,
+        };
+        let entry = match self.get(year, day) {
+            None => return InputStatus::Untracked,
+            Some(entry) => entry,
+        };
+
+        if entry.sha256 != sha256_hex(contents) {
+            InputStatus::Modified
+        } else if account.is_some()
+            && entry.account.is_some()
+            && entry.account.as_deref() != account
+        {
+            InputStatus::OtherAccount
+        } else {
+            InputStatus::Ok
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256() {
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(account_id("abc\n"), "ba7816bf8f01cfea");
+    }
+
+    #[test]
+    fn test_format_utc() {
+        assert_eq!(format_utc(0), "1970-01-01 00:00 UTC");
+        assert_eq!(format_utc(1669870800), "2022-12-01 05:00 UTC");
+        assert_eq!(format_utc(1709208000 + 59), "2024-02-29 12:00 UTC");
+    }
+
+    #[test]
+    fn test_status() {
+        let mut manifest = Manifest::default();
+        manifest.record(2022, 1, "1000\n", Some(account_id("abc")));
+        manifest.record(2022, 1, "2000\n", Some(account_id("abc")));
+        assert_eq!(manifest.inputs.len(), 1);
+
+        let account = account_id("abc");
+        let status = |day, contents: Option<&str>, account: Option<&str>| {
+            manifest.status(2022, day, contents.map(str::as_bytes), account)
+        };
+        assert_eq!(status(1, Some("2000\n"), Some(&account)), InputStatus::Ok);
+        assert_eq!(status(1, Some("2000\n"), None), InputStatus::Ok);
+        assert_eq!(status(1, Some("1000\n"), None), InputStatus::Modified);
+        assert_eq!(
+            status(1, Some("2000\n"), Some("0123456789abcdef")),
+            InputStatus::OtherAccount
+        );
+        assert_eq!(status(2, Some("3\n"), None), InputStatus::Untracked);
+        assert_eq!(status(2, Some("\n"), None), InputStatus::Empty);
+        assert_eq!(status(2, None, None), InputStatus::Missing);
+
+        let json = serde_json::to_string(&manifest).unwrap();
+        assert_eq!(serde_json::from_str::<Manifest>(&json).unwrap(), manifest);
+    }
+}
@@ -0,0 +1,166 @@
+/*
+ * This file contains template code.
+ * Heap usage of a part, counted by a global allocator when the `memory-stats` feature is on.
+ */
+use std::fmt::{self, Display};
+
+/// Heap usage of one run of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// Most bytes allocated at the same time, on top of what was allocated before the run.
+    pub peak_bytes: usize,
+    /// Calls to `alloc` and `realloc`.
+    pub allocations: usize,
+}
+
+impl Display for MemoryStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+        let mut size = self.peak_bytes as f64;
+        let mut unit = 0;
+        while size >= 1024_f64 && unit < UNITS.len() - 1 {
+            size /= 1024_f64;
+            unit += 1;
+        }
+        if unit == 0 {
+            write!(f, "peak: {} B", self.peak_bytes)?;
+        } else {
+            write!(f, "peak: {:.2} {}", size, UNITS[unit])?;
+        }
+        write!(f, ", allocations: {}", self.allocations)
+    }
+}
+
+/// Runs `f` and returns the heap usage of the current thread while it ran. Allocations of
+/// threads spawned by `f` are not counted. Always `None` without the `memory-stats` feature.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<MemoryStats>) {
+    #[cfg(feature = "memory-stats")]
+    {
+        counting::reset();
+        let result = f();
+        (result, Some(counting::stats()))
+    }
+    #[cfg(not(feature = "memory-stats"))]
+    (f(), None)
+}
+
+#[cfg(feature = "memory-stats")]
+mod counting {
+    use super::MemoryStats;
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+
+    struct Counters {
+        // memory freed on this thread may have been allocated elsewhere, so this can go negative.
+        current: Cell<isize>,
+        peak: Cell<isize>,
+        allocations: Cell<usize>,
+    }
+
+    thread_local! {
+        // const, so accessing it from the allocator never allocates itself.
+        static COUNTERS: Counters = const {
+            Counters {
+                current: Cell::new(0),
+                peak: Cell::new(0),
+                allocations: Cell::new(0),
+            }
+        };
+    }
+
+    fn record(change: isize, allocation: bool) {
+        // fails while the thread is shutting down, those allocations are not interesting anyway.
+        let _ = COUNTERS.try_with(|c| {
+            let current = c.current.get() + change;
+            c.current.set(current);
+            c.peak.set(c.peak.get().max(current));
+            if allocation {
+                c.allocations.set(c.allocations.get() + 1);
+            }
+        });
+    }
+
+    pub fn reset() {
+        COUNTERS.with(|c| {
+            c.current.set(0);
+            c.peak.set(0);
+            c.allocations.set(0);
+        });
+    }
+
+    pub fn stats() -> MemoryStats {
+        COUNTERS.with(|c| MemoryStats {
+            peak_bytes: c.peak.get().max(0) as usize,
+            allocations: c.allocations.get(),
+        })
+    }
+
+    struct CountingAllocator;
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc(layout);
+            if !ptr.is_null() {
+                record(layout.size() as isize, true);
+            }
+            ptr
+        }
+
+        unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+            let ptr = System.alloc_zeroed(layout);
+            if !ptr.is_null() {
+                record(layout.size() as isize, true);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            System.dealloc(ptr, layout);
+            record(-(layout.size() as isize), false);
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = System.realloc(ptr, layout, new_size);
+            if !new_ptr.is_null() {
+                record(new_size as isize - layout.size() as isize, true);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_stats_display() {
+        let stats = MemoryStats {
+            peak_bytes: 512,
+            allocations: 3,
+        };
+        assert_eq!(stats.to_string(), "peak: 512 B, allocations: 3");
+
+        let stats = MemoryStats {
+            peak_bytes: 3 * 1024 * 1024 / 2,
+            allocations: 1,
+        };
+        assert_eq!(stats.to_string(), "peak: 1.50 MiB, allocations: 1");
+    }
+
+    #[cfg(feature = "memory-stats")]
+    #[test]
+    fn test_measure() {
+        let (_, stats) = measure(|| {
+            let small = vec![0_u8; 1000];
+            drop(small);
+            vec![0_u64; 100].len()
+        });
+        let stats = stats.unwrap();
+        assert_eq!(stats.peak_bytes, 1000);
+        assert_eq!(stats.allocations, 2);
+    }
+}
@@ -0,0 +1,168 @@
+/*
+ * This file contains template code.
+ * Answers that are drawn instead of computed, like the screen of 2022 day 10.
+ */
+use std::fmt::{self, Display};
+
+const LIT: char = '#';
+const DARK: char = '.';
+
+/// Letters are 4 pixels wide and 6 pixels high, with one empty column after each letter.
+const GLYPH_WIDTH: usize = 4;
+const GLYPH_HEIGHT: usize = 6;
+const GLYPH_STRIDE: usize = GLYPH_WIDTH + 1;
+
+/// The letters of the Advent of Code font that have shown up in puzzles so far.
+const GLYPHS: &[(char, [&str; GLYPH_HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// A monochrome image. Displays as the letters it shows if all of them are recognised, and as
+/// ASCII art otherwise. Use `{:#}` to always get the ASCII art.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bitmap {
+    width: usize,
+    height: usize,
+    pixels: Vec<bool>,
+}
+
+impl Bitmap {
+    /// `pixels` are row by row, `true` is a lit pixel.
+    pub fn new(width: usize, height: usize, pixels: Vec<bool>) -> Self {
+        assert_eq!(pixels.len(), width * height, "bitmap has the wrong size.");
+        Bitmap {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// Parses ASCII art where `#` is a lit pixel and anything else is dark.
+    pub fn from_ascii(art: &str) -> Self {
+        let rows = art.lines().collect::<Vec<_>>();
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let pixels = rows
+            .iter()
+            .flat_map(|row| {
+                let row = row.chars().map(|c| c == LIT).collect::<Vec<_>>();
+                (0..width).map(move |x| row.get(x).copied().unwrap_or(false))
+            })
+            .collect();
+        Bitmap::new(width, rows.len(), pixels)
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.pixels[y * self.width + x]
+    }
+
+    /// Recognises the letters of the Advent of Code font, `None` if any glyph is unknown.
+    pub fn letters(&self) -> Option<String> {
+        if self.height != GLYPH_HEIGHT || self.width < GLYPH_WIDTH {
+            return None;
+        }
+
+        (0..self.width.div_ceil(GLYPH_STRIDE))
+            .map(|i| self.glyph_at(i * GLYPH_STRIDE))
+            .collect()
+    }
+
+    fn glyph_at(&self, left: usize) -> Option<char> {
+        GLYPHS
+            .iter()
+            .find(|(_, rows)| {
+                rows.iter().enumerate().all(|(y, row)| {
+                    row.chars()
+                        .enumerate()
+                        .all(|(x, c)| self.get(left + x, y) == (c == LIT))
+                })
+            })
+            .map(|(letter, _)| *letter)
+    }
+
+    fn write_ascii(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.pixels.chunks(self.width.max(1)).enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for pixel in row {
+                write!(f, "{}", if *pixel { LIT } else { DARK })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Display for Bitmap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.letters() {
+            Some(letters) if !f.alternate() => write!(f, "{}", letters),
+            _ => self.write_ascii(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_letters() {
+        let art = "\
+###..####.#..#.####.####.#..#..##..####.
+#..#.#....#..#....#.#....#..#.#..#....#.
+#..#.###..####...#..###..#..#.#......#..
+###..#....#..#..#...#....#..#.#.....#...
+#.#..#....#..#.#....#....#..#.#..#.#....
+#..#.####.#..#.####.#.....##...##..####.";
+        let bitmap = Bitmap::from_ascii(art);
+        assert_eq!(bitmap.letters(), Some("REHZFUCZ".to_string()));
+        assert_eq!(bitmap.to_string(), "REHZFUCZ");
+        assert_eq!(format!("{:#}", bitmap), art);
+
+        let art = "\
+.##..####
+#..#.#...
+#..#.###.
+####.#...
+#..#.#...
+#..#.####";
+        assert_eq!(Bitmap::from_ascii(art).letters(), Some("AE".to_string()));
+        let art = ".###.#...\n..#..#...\n..#..#...\n..#..#...\n..#..#...\n.###.####";
+        assert_eq!(Bitmap::from_ascii(art).letters(), Some("IL".to_string()));
+        // a letter that is cut off is not recognised.
+        let art = art
+            .lines()
+            .map(|row| &row[..8])
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(Bitmap::from_ascii(&art).letters(), None);
+    }
+
+    #[test]
+    fn test_unknown_glyphs() {
+        let art = "##..\n#...\n#..#\n....\n....\n.##.";
+        let bitmap = Bitmap::from_ascii(art);
+        assert_eq!(bitmap.letters(), None);
+        assert_eq!(bitmap.to_string(), art);
+    }
+}
@@ -0,0 +1,386 @@
+/*
+ * This file contains template code.
+ * Output formats for the results of `solve!` and the all-days runner.
+ */
+use std::fmt::{self, Display};
+use std::str::FromStr;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::input::InputError;
+use crate::memory::MemoryStats;
+use crate::runner::Stats;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected one of: text, json, csv",
+                s
+            )),
+        }
+    }
+}
+
+impl Format {
+    pub fn print_header(&self) {
+        if *self == Format::Csv {
+            println!("{}", CSV_HEADER);
+        }
+    }
+
+    pub fn print_record(&self, record: &Record) {
+        match self {
+            Format::Text => print!("{}", record),
+            Format::Json => println!("{}", record.to_json()),
+            Format::Csv => println!("{}", record.to_csv()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    MissingInput,
+    InvalidInput,
+    Timeout,
+    Panicked,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::MissingInput => write!(f, "missing_input"),
+            Status::InvalidInput => write!(f, "invalid_input"),
+            Status::Timeout => write!(f, "timeout"),
+            Status::Panicked => write!(f, "panicked"),
+        }
+    }
+}
+
+pub const CSV_HEADER: &str =
+    "day,part,answer,duration_ns,status,error,iterations,min_ns,median_ns,mean_ns,stddev_ns,peak_bytes,allocations";
+
+/// The outcome of running one part of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    pub status: Status,
+    /// Only set when the part was benchmarked.
+    pub stats: Option<Stats>,
+    /// Why the part did not run or failed, e.g. the panic message.
+    pub error: Option<String>,
+    /// Only set with the `memory-stats` feature.
+    pub memory: Option<MemoryStats>,
+}
+
+impl Record {
+    pub fn new<T: Display>(day: u8, part: u8, answer: Option<T>, elapsed: Duration) -> Self {
+        let status = match answer {
+            Some(_) => Status::Solved,
+            None => Status::Unsolved,
+        };
+        Record {
+            day,
+            part,
+            answer: answer.map(|a| a.to_string()),
+            elapsed,
+            status,
+            stats: None,
+            error: None,
+            memory: None,
+        }
+    }
+
+    pub fn input_error(day: u8, part: u8, error: &InputError) -> Self {
+        Record {
+            day,
+            part,
+            answer: None,
+            elapsed: Duration::ZERO,
+            status: if error.is_missing() {
+                Status::MissingInput
+            } else {
+                Status::InvalidInput
+            },
+            stats: None,
+            error: Some(error.to_string()),
+            memory: None,
+        }
+    }
+
+    pub fn timeout(day: u8, part: u8, elapsed: Duration) -> Self {
+        Record {
+            day,
+            part,
+            answer: None,
+            elapsed,
+            status: Status::Timeout,
+            stats: None,
+            error: None,
+            memory: None,
+        }
+    }
+
+    pub fn panicked(day: u8, part: u8, elapsed: Duration, message: String) -> Self {
+        Record {
+            day,
+            part,
+            answer: None,
+            elapsed,
+            status: Status::Panicked,
+            stats: None,
+            error: Some(message),
+            memory: None,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&JsonRecord::from(self)).unwrap()
+    }
+
+    pub fn to_csv(&self) -> String {
+        let stats = self.stats.map_or(",,,,".to_string(), |s| {
+            format!(
+                "{},{},{},{},{}",
+                s.iterations,
+                s.min.as_nanos(),
+                s.median.as_nanos(),
+                s.mean.as_nanos(),
+                s.stddev.as_nanos()
+            )
+        });
+        let memory = self.memory.map_or(",".to_string(), |m| {
+            format!("{},{}", m.peak_bytes, m.allocations)
+        });
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            self.day,
+            self.part,
+            self.answer.as_ref().map_or(String::new(), |a| csv_field(a)),
+            self.elapsed.as_nanos(),
+            self.status,
+            self.error.as_ref().map_or(String::new(), |e| csv_field(e)),
+            stats,
+            memory
+        )
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "🎄 {}Part {}{} 🎄", ANSI_BOLD, self.part, ANSI_RESET)?;
+        match &self.answer {
+            // keeps the timing out of the last row of rendered answers.
+            Some(answer) if answer.contains('\n') => write!(
+                f,
+                "{}\n{}(elapsed: {:.2?}){}",
+                answer, ANSI_ITALIC, self.elapsed, ANSI_RESET
+            )?,
+            Some(answer) => write!(
+                f,
+                "{} {}(elapsed: {:.2?}){}",
+                answer, ANSI_ITALIC, self.elapsed, ANSI_RESET
+            )?,
+            None => match self.status {
+                Status::Timeout => write!(f, "timeout after {:.2?}.", self.elapsed)?,
+                Status::Panicked => {
+                    write!(f, "panicked: {}", self.error.as_deref().unwrap_or_default())?
+                }
+                Status::MissingInput | Status::InvalidInput if self.error.is_some() => {
+                    write!(f, "{}", self.error.as_deref().unwrap_or_default())?
+                }
+                _ => write!(f, "not solved.")?,
+            },
+        }
+        if let Some(stats) = self.stats {
+            write!(
+                f,
+                " {}[min: {:.2?}, median: {:.2?}, mean: {:.2?}, stddev: {:.2?}, runs: {}]{}",
+                ANSI_ITALIC,
+                stats.min,
+                stats.median,
+                stats.mean,
+                stats.stddev,
+                stats.iterations,
+                ANSI_RESET
+            )?;
+        }
+        if let Some(memory) = self.memory {
+            write!(f, " {}[{}]{}", ANSI_ITALIC, memory, ANSI_RESET)?;
+        }
+        writeln!(f)
+    }
+}
+
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a str>,
+    duration_ns: u128,
+    status: Status,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+    #[serde(flatten)]
+    stats: Option<JsonStats>,
+    #[serde(flatten)]
+    memory: Option<JsonMemory>,
+}
+
+#[derive(Serialize)]
+struct JsonStats {
+    iterations: usize,
+    min_ns: u128,
+    median_ns: u128,
+    mean_ns: u128,
+    stddev_ns: u128,
+}
+
+#[derive(Serialize)]
+struct JsonMemory {
+    peak_bytes: usize,
+    allocations: usize,
+}
+
+impl<'a> From<&'a Record> for JsonRecord<'a> {
+    fn from(record: &'a Record) -> Self {
+        JsonRecord {
+            day: record.day,
+            part: record.part,
+            answer: record.answer.as_deref(),
+            duration_ns: record.elapsed.as_nanos(),
+            status: record.status,
+            error: record.error.as_deref(),
+            stats: record.stats.map(|s| JsonStats {
+                iterations: s.iterations,
+                min_ns: s.min.as_nanos(),
+                median_ns: s.median.as_nanos(),
+                mean_ns: s.mean.as_nanos(),
+                stddev_ns: s.stddev.as_nanos(),
+            }),
+            memory: record.memory.map(|m| JsonMemory {
+                peak_bytes: m.peak_bytes,
+                allocations: m.allocations,
+            }),
+        }
+    }
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_formats() {
+        let record = Record::new(5, 1, Some("CMZ"), Duration::from_nanos(1500));
+        assert_eq!(
+            record.to_json(),
+            "{\"day\":5,\"part\":1,\"answer\":\"CMZ\",\"duration_ns\":1500,\"status\":\"solved\"}"
+        );
+        assert_eq!(record.to_csv(), "5,1,CMZ,1500,solved,,,,,,,,");
+
+        let record = Record::new(7, 2, Some("a,\"b\"\nc"), Duration::from_nanos(3));
+        assert_eq!(
+            record.to_json(),
+            "{\"day\":7,\"part\":2,\"answer\":\"a,\\\"b\\\"\\nc\",\"duration_ns\":3,\"status\":\"solved\"}"
+        );
+        assert_eq!(record.to_csv(), "7,2,\"a,\"\"b\"\"\nc\",3,solved,,,,,,,,");
+
+        let error = InputError::Missing {
+            path: "inputs/03.txt".into(),
+            download: None,
+        };
+        let record = Record::input_error(3, 2, &error);
+        assert_eq!(
+            record.to_json(),
+            "{\"day\":3,\"part\":2,\"answer\":null,\"duration_ns\":0,\"status\":\"missing_input\",\"error\":\"input file \\\"inputs/03.txt\\\" does not exist.\"}"
+        );
+        assert_eq!(
+            record.to_csv(),
+            "3,2,,0,missing_input,\"input file \"\"inputs/03.txt\"\" does not exist.\",,,,,,,"
+        );
+
+        let mut record = Record::new(1, 1, Some(42), Duration::from_nanos(20));
+        record.stats = Some(Stats {
+            iterations: 3,
+            min: Duration::from_nanos(10),
+            median: Duration::from_nanos(20),
+            mean: Duration::from_nanos(25),
+            stddev: Duration::from_nanos(5),
+        });
+        assert_eq!(
+            record.to_json(),
+            "{\"day\":1,\"part\":1,\"answer\":\"42\",\"duration_ns\":20,\"status\":\"solved\",\"iterations\":3,\"min_ns\":10,\"median_ns\":20,\"mean_ns\":25,\"stddev_ns\":5}"
+        );
+        assert_eq!(record.to_csv(), "1,1,42,20,solved,,3,10,20,25,5,,");
+
+        let record = Record::panicked(19, 2, Duration::from_nanos(7), "oh, no".to_string());
+        assert_eq!(
+            record.to_json(),
+            "{\"day\":19,\"part\":2,\"answer\":null,\"duration_ns\":7,\"status\":\"panicked\",\"error\":\"oh, no\"}"
+        );
+        assert_eq!(record.to_csv(), "19,2,,7,panicked,\"oh, no\",,,,,,,");
+
+        let mut record = Record::new(4, 1, Some(2), Duration::from_nanos(9));
+        record.memory = Some(MemoryStats {
+            peak_bytes: 2048,
+            allocations: 4,
+        });
+        assert_eq!(
+            record.to_json(),
+            "{\"day\":4,\"part\":1,\"answer\":\"2\",\"duration_ns\":9,\"status\":\"solved\",\"peak_bytes\":2048,\"allocations\":4}"
+        );
+        assert_eq!(record.to_csv(), "4,1,2,9,solved,,,,,,,2048,4");
+
+        let record = Record::new(10, 2, Some("#.\n.#"), Duration::from_nanos(2));
+        assert_eq!(
+            record.to_string(),
+            format!(
+                "🎄 {}Part 2{} 🎄\n#.\n.#\n{}(elapsed: 2.00ns){}\n",
+                ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, ANSI_RESET
+            )
+        );
+
+        let error = InputError::CrlfLineEndings {
+            path: "01.txt".into(),
+        };
+        let record = Record::input_error(1, 1, &error);
+        assert_eq!(
+            record.to_string(),
+            format!(
+                "🎄 {}Part 1{} 🎄\ninput file \"01.txt\" has CRLF line endings - convert the file to LF line endings.\n",
+                ANSI_BOLD, ANSI_RESET
+            )
+        );
+    }
+}
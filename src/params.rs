@@ -0,0 +1,143 @@
+/*
+ * This file contains template code.
+ * Typed parameters for puzzles where the example and the real input need different constants.
+ */
+use std::fmt::Display;
+use std::process;
+use std::str::FromStr;
+
+/// Parameters of a day, usually declared with `params!`.
+pub trait Params: Sized {
+    /// Values for the real input.
+    fn real() -> Self;
+    /// Values for the example.
+    fn example() -> Self;
+    /// Overrides the parameter `key`. Fails for unknown keys and unparsable values.
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String>;
+
+    /// Applies overrides given as `key=value`.
+    fn with_overrides(mut self, overrides: &[String]) -> Result<Self, String> {
+        for param in overrides {
+            let (key, value) = param
+                .split_once('=')
+                .ok_or_else(|| format!("invalid parameter \"{}\", expected key=value", param))?;
+            self.set(key.trim(), value.trim())?;
+        }
+        Ok(self)
+    }
+
+    /// Reads `--param key=value` (repeatable) from the command line. `--example` starts from the
+    /// example values instead of the real ones. Exits on invalid parameters.
+    fn from_env() -> Self {
+        let mut args = pico_args::Arguments::from_env();
+        let defaults = match args.contains("--example") {
+            true => Self::example(),
+            false => Self::real(),
+        };
+        let overrides = args
+            .values_from_str::<_, String>("--param")
+            .map_err(|e| e.to_string())
+            .and_then(|overrides| defaults.with_overrides(&overrides));
+        match overrides {
+            Ok(params) => params,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+/// Parses the value of a parameter, used by `params!`.
+pub fn parse_value<T: FromStr>(key: &str, value: &str) -> Result<T, String>
+where
+    T::Err: Display,
+{
+    value.parse().map_err(|e| {
+        format!(
+            "invalid value \"{}\" for parameter \"{}\": {}",
+            value, key, e
+        )
+    })
+}
+
+/// Declares the parameters of a day together with their values for the real input and the
+/// example. The field names are the keys for `--param`.
+///
+/// ```ignore
+/// advent_of_code::params! {
+///     pub struct Params {
+///         row: isize = { real: 2000000, example: 10 },
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident: $ty:ty = { real: $real:expr, example: $example:expr }
+            ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        $vis struct $name {
+            $($(#[$field_meta])* pub $field: $ty),*
+        }
+
+        impl $crate::params::Params for $name {
+            fn real() -> Self {
+                $name { $($field: $real),* }
+            }
+
+            fn example() -> Self {
+                $name { $($field: $example),* }
+            }
+
+            fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+                match key {
+                    $(stringify!($field) => self.$field = $crate::params::parse_value(key, value)?,)*
+                    _ => return Err(format!("unknown parameter \"{}\"", key)),
+                }
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::params! {
+        struct TestParams {
+            row: isize = { real: 2000000, example: 10 },
+            rounds: usize = { real: 3, example: 2 },
+        }
+    }
+
+    #[test]
+    fn test_params() {
+        assert_eq!(TestParams::example().row, 10);
+
+        let params = TestParams::real()
+            .with_overrides(&["row=10".to_string(), " rounds = 5".to_string()])
+            .unwrap();
+        assert_eq!(params, TestParams { row: 10, rounds: 5 });
+
+        let invalid = |param: &str| {
+            TestParams::real()
+                .with_overrides(&[param.to_string()])
+                .unwrap_err()
+        };
+        assert_eq!(
+            invalid("row"),
+            "invalid parameter \"row\", expected key=value"
+        );
+        assert_eq!(invalid("col=1"), "unknown parameter \"col\"");
+        assert!(invalid("rounds=-1").starts_with("invalid value \"-1\" for parameter \"rounds\""));
+    }
+}
@@ -0,0 +1,198 @@
+/*
+ * This file contains template code.
+ * Turns the puzzle page into Markdown for `src/<year>/puzzles/NN.md` and picks the example
+ * out of it. Only the markup the puzzle descriptions actually use is understood.
+ */
+use std::path::PathBuf;
+
+pub fn puzzle_path(year: u16, day: u8) -> PathBuf {
+    crate::year_dir("puzzles", year).join(format!("{:02}.md", day))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token<'a> {
+    Open { name: String, href: Option<String> },
+    Close { name: String },
+    Text(&'a str),
+}
+
+/// Splits HTML into tags and text. Comments, doctypes and malformed tags are dropped.
+fn tokenize(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    let mut rest = html;
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        rest = &rest[start + end + 1..];
+
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        if name.is_empty() || name.starts_with('!') {
+            continue;
+        }
+        if tag.starts_with('/') {
+            tokens.push(Token::Close { name });
+        } else {
+            let href = tag
+                .split_once("href=\"")
+                .and_then(|(_, rest)| rest.split_once('"'))
+                .map(|(href, _)| decode_entities(href));
+            tokens.push(Token::Open { name, href });
+        }
+    }
+    tokens
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Converts the puzzle descriptions (the `<article>` elements) of a puzzle page to Markdown.
+/// Part two is included once it is unlocked.
+pub fn to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut article_depth = 0;
+    let mut in_pre = false;
+    let mut links = vec![];
+
+    for token in tokenize(html) {
+        if let Token::Open { name, .. } = &token {
+            if name == "article" {
+                article_depth += 1;
+                continue;
+            }
+        }
+        if let Token::Close { name } = &token {
+            if name == "article" {
+                article_depth -= 1;
+                markdown.push_str("\n\n");
+                continue;
+            }
+        }
+        if article_depth == 0 {
+            continue;
+        }
+
+        match token {
+            // line breaks between tags, the closing tags add the ones Markdown needs.
+            Token::Text(text) if !in_pre && text.trim().is_empty() && text.contains('\n') => {}
+            Token::Text(text) => markdown.push_str(&decode_entities(text)),
+            Token::Open { name, href } => match name.as_str() {
+                "h2" => markdown.push_str("## "),
+                "pre" => {
+                    in_pre = true;
+                    markdown.push_str("```\n");
+                }
+                "code" if !in_pre => markdown.push('`'),
+                "em" if !in_pre => markdown.push('*'),
+                "li" => markdown.push_str("- "),
+                "br" => markdown.push('\n'),
+                "a" => {
+                    markdown.push('[');
+                    links.push(href);
+                }
+                _ => {}
+            },
+            Token::Close { name } => match name.as_str() {
+                "h2" | "p" | "ul" => markdown.push_str("\n\n"),
+                "pre" => {
+                    in_pre = false;
+                    if !markdown.ends_with('\n') {
+                        markdown.push('\n');
+                    }
+                    markdown.push_str("```\n\n");
+                }
+                "code" if !in_pre => markdown.push('`'),
+                "em" if !in_pre => markdown.push('*'),
+                "li" => markdown.push('\n'),
+                "a" => match links.pop().flatten() {
+                    Some(href) => markdown.push_str(&format!("]({})", href)),
+                    None => markdown.push(']'),
+                },
+                _ => {}
+            },
+        }
+    }
+
+    let mut result = String::new();
+    for line in markdown.trim().lines() {
+        if line.trim().is_empty() && (result.is_empty() || result.ends_with("\n\n")) {
+            continue;
+        }
+        result.push_str(line.trim_end());
+        result.push('\n');
+    }
+    result
+}
+
+/// The first code block of the puzzle description, which usually is the example input.
+pub fn extract_example(html: &str) -> Option<String> {
+    let mut in_article = false;
+    let mut in_pre = false;
+    let mut example = String::new();
+
+    for token in tokenize(html) {
+        match token {
+            Token::Open { name, .. } if name == "article" => in_article = true,
+            Token::Open { name, .. } if name == "pre" && in_article => in_pre = true,
+            Token::Close { name } if name == "pre" && in_pre => break,
+            Token::Text(text) if in_pre => example.push_str(&decode_entities(text)),
+            _ => {}
+        }
+    }
+
+    let example = example.trim_start_matches('\n');
+    if example.trim().is_empty() {
+        return None;
+    }
+    Some(match example.ends_with('\n') {
+        true => example.to_string(),
+        false => format!("{}\n", example),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = include_str!("../fixtures/puzzle.html");
+    const MARKDOWN: &str = include_str!("../fixtures/puzzle.md");
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(to_markdown(PAGE), MARKDOWN);
+    }
+
+    #[test]
+    fn test_extract_example() {
+        assert_eq!(
+            extract_example(PAGE),
+            Some("1000\n2000\n\n3000\n<4000>\n".to_string())
+        );
+        assert_eq!(
+            extract_example("<article><p>No example.</p></article>"),
+            None
+        );
+        // code blocks outside of the description are not examples.
+        assert_eq!(extract_example("<pre><code>1\n</code></pre>"), None);
+    }
+}
@@ -0,0 +1,150 @@
+/*
+ * This file contains template code.
+ * Generates the timings table in README.md from the records of the runner. The table lives
+ * between `START_MARKER` and `END_MARKER`, everything else in the README is left alone,
+ * including the stars table of the `readme-stars` workflow.
+ */
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::answers::{Answers, Verdict};
+use crate::client::DEFAULT_BASE_URL;
+use crate::output::{Record, Status};
+
+pub const START_MARKER: &str = "<!--- results table start --->";
+pub const END_MARKER: &str = "<!--- results table end --->";
+
+/// Solved parts only get a star if they match the accepted answer, as any answer counts as
+/// solved for the runner.
+fn cell(record: Option<&Record>, accepted: Option<&Answers>) -> String {
+    let Some(record) = record else {
+        return "-".to_string();
+    };
+    match record.status {
+        Status::Solved => {
+            let verdict = accepted.map(|a| a.verify(record.part, record.answer.as_deref()));
+            match verdict {
+                Some(Verdict::Match) => format!("⭐ {:.2?}", record.elapsed),
+                Some(Verdict::Mismatch { .. }) => format!("❌ {:.2?}", record.elapsed),
+                _ => format!("{:.2?}", record.elapsed),
+            }
+        }
+        Status::Unsolved => "not solved".to_string(),
+        Status::MissingInput => "missing input".to_string(),
+        Status::InvalidInput => "invalid input".to_string(),
+        Status::Timeout => "timeout".to_string(),
+        Status::Panicked => "panicked".to_string(),
+    }
+}
+
+fn solved_time<'a>(records: impl IntoIterator<Item = &'a Record>) -> Duration {
+    records
+        .into_iter()
+        .filter(|r| r.status == Status::Solved)
+        .map(|r| r.elapsed)
+        .sum()
+}
+
+/// The Markdown for the records of one year, one row per day. `accepted` holds the accepted
+/// answers by day, see `Answers::read`.
+pub fn results_table(year: u16, records: &[Record], accepted: &BTreeMap<u8, Answers>) -> String {
+    let mut days = records.iter().map(|r| r.day).collect::<Vec<_>>();
+    days.dedup();
+
+    let mut table = format!("## {} Timings\n\n", year);
+    table.push_str("| Day | Part 1 | Part 2 | Time |\n");
+    table.push_str("| :---: | :---: | :---: | :---: |\n");
+    for day in days {
+        let day_records = records.iter().filter(|r| r.day == day).collect::<Vec<_>>();
+        let part = |part| day_records.iter().copied().find(|r| r.part == part);
+        let time = match day_records.iter().any(|r| r.status == Status::Solved) {
+            true => format!("{:.2?}", solved_time(day_records.iter().copied())),
+            false => "-".to_string(),
+        };
+        table.push_str(&format!(
+            "| [Day {}]({}/{}/day/{}) | {} | {} | {} |\n",
+            day,
+            DEFAULT_BASE_URL,
+            year,
+            day,
+            cell(part(1), accepted.get(&day)),
+            cell(part(2), accepted.get(&day)),
+            time
+        ));
+    }
+
+    let solved = records
+        .iter()
+        .filter(|r| r.status == Status::Solved)
+        .count();
+    table.push_str(&format!(
+        "\n**Total:** {:.2}ms, {} of {} parts solved\n",
+        solved_time(records).as_secs_f64() * 1000_f64,
+        solved,
+        records.len()
+    ));
+    table
+}
+
+/// Replaces the block between the markers with `table`.
+pub fn replace_block(readme: &str, table: &str) -> Result<String, String> {
+    let missing = |marker| format!("README.md has no \"{}\" line", marker);
+    let start = readme
+        .find(START_MARKER)
+        .ok_or_else(|| missing(START_MARKER))?;
+    let end = readme[start..]
+        .find(END_MARKER)
+        .map(|end| start + end)
+        .ok_or_else(|| missing(END_MARKER))?;
+
+    Ok(format!(
+        "{}{}\n{}{}",
+        &readme[..start],
+        START_MARKER,
+        table,
+        &readme[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_results_table() {
+        let records = [
+            Record::new(1, 1, Some(24000), Duration::from_micros(20)),
+            Record::new(1, 2, Some(45000), Duration::from_micros(30)),
+            Record::new::<u32>(2, 1, None, Duration::from_micros(5)),
+            Record::new(2, 2, Some(0), Duration::from_micros(1)),
+            Record::new(3, 1, Some(7), Duration::from_micros(2)),
+        ];
+        let accepted = BTreeMap::from([(1, Answers::parse("24000\n1\n"))]);
+        assert_eq!(
+            results_table(2022, &records, &accepted),
+            "## 2022 Timings\n\n\
+             | Day | Part 1 | Part 2 | Time |\n\
+             | :---: | :---: | :---: | :---: |\n\
+             | [Day 1](https://adventofcode.com/2022/day/1) | ⭐ 20.00µs | ❌ 30.00µs | 50.00µs |\n\
+             | [Day 2](https://adventofcode.com/2022/day/2) | not solved | 1.00µs | 1.00µs |\n\
+             | [Day 3](https://adventofcode.com/2022/day/3) | 2.00µs | - | 2.00µs |\n\
+             \n**Total:** 0.05ms, 4 of 5 parts solved\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_block() {
+        let readme = format!(
+            "# AoC\n\n{}\nold\n{}\n\n## Friends\n",
+            START_MARKER, END_MARKER
+        );
+        assert_eq!(
+            replace_block(&readme, "new\n").unwrap(),
+            format!(
+                "# AoC\n\n{}\nnew\n{}\n\n## Friends\n",
+                START_MARKER, END_MARKER
+            )
+        );
+        assert!(replace_block("# AoC\n", "new\n").is_err());
+    }
+}
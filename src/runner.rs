@@ -0,0 +1,370 @@
+/*
+ * This file contains template code.
+ * Shared logic for running (and benchmarking) a single part, used by `solve!` and the all-days runner.
+ */
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::days::{Solution, Solver};
+use crate::memory;
+use crate::output::{Format, Record};
+
+/// Options shared by every day binary and the all-days runner.
+#[derive(Debug, Clone, Copy)]
+pub struct Options {
+    pub format: Format,
+    /// Only run this part, `None` to run both parts.
+    pub part: Option<u8>,
+    /// Number of measured iterations per part, `None` to run each part once.
+    pub bench: Option<usize>,
+    /// Time limit per part. Only enforced by `run_day`, `solve!` runs the part directly.
+    pub timeout: Option<Duration>,
+}
+
+impl Options {
+    pub fn from_args(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Options {
+            format: args.opt_value_from_str("--format")?.unwrap_or(Format::Text),
+            part: args.opt_value_from_fn(["-p", "--part"], parse_part)?,
+            bench: args
+                .opt_value_from_str("--bench")?
+                .filter(|iterations| *iterations > 0),
+            timeout: None,
+        })
+    }
+
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    /// Reads the options from the command line. Exits on invalid values.
+    pub fn from_env() -> Self {
+        match Self::from_args(&mut pico_args::Arguments::from_env()) {
+            Ok(options) => options,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {}", e);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    match value.parse() {
+        Ok(part @ (1 | 2)) => Ok(part),
+        _ => Err("expected 1 or 2".to_string()),
+    }
+}
+
+/// Timing statistics over repeated runs of a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().map(|d| d.as_secs_f64()).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            iterations: n,
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Untimed runs before measuring, so caches and the allocator are warmed up.
+fn warmup_iterations(iterations: usize) -> usize {
+    (iterations / 10).clamp(1, 10)
+}
+
+pub fn time_part<T>(func: impl FnOnce(&str) -> Option<T>, input: &str) -> (Option<T>, Duration) {
+    let timer = Instant::now();
+    let result = func(input);
+    (result, timer.elapsed())
+}
+
+pub fn bench_part<T>(
+    func: impl Fn(&str) -> Option<T>,
+    input: &str,
+    iterations: usize,
+) -> (Option<T>, Stats) {
+    for _ in 0..warmup_iterations(iterations) {
+        func(input);
+    }
+
+    let mut result = None;
+    let samples: Vec<Duration> = (0..iterations)
+        .map(|_| {
+            let (r, elapsed) = time_part(&func, input);
+            result = r;
+            elapsed
+        })
+        .collect();
+
+    (result, Stats::from_samples(&samples))
+}
+
+/// Runs one part according to `options`. When benchmarking, the reported time is the median.
+pub fn run_part<T: Display>(
+    day: u8,
+    part: u8,
+    func: impl Fn(&str) -> Option<T>,
+    input: &str,
+    options: &Options,
+) -> Record {
+    match options.bench {
+        Some(iterations) => {
+            // one more run, so allocations are not counted once per iteration.
+            let (_, memory) = memory::measure(|| func(input));
+            let (result, stats) = bench_part(func, input, iterations);
+            let mut record = Record::new(day, part, result, stats.median);
+            record.stats = Some(stats);
+            record.memory = memory;
+            record
+        }
+        None => {
+            let ((result, elapsed), memory) = memory::measure(|| time_part(func, input));
+            let mut record = Record::new(day, part, result, elapsed);
+            record.memory = memory;
+            record
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
+
+/// Runs one part on its own thread, so a panic or a hang cannot take down the caller.
+/// A part that exceeds `options.timeout` is abandoned; its thread keeps running in the
+/// background until the process exits.
+pub fn run_part_isolated(
+    day: u8,
+    part: u8,
+    solver: Solver,
+    input: Arc<str>,
+    options: &Options,
+) -> Record {
+    let (sender, receiver) = mpsc::channel();
+    let thread_options = *options;
+    let timer = Instant::now();
+
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            run_part(day, part, solver, &input, &thread_options)
+        }));
+        // the receiver is gone if the part timed out.
+        sender.send(result).ok();
+    });
+
+    let result = match options.timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).ok(),
+        None => receiver.recv().ok(),
+    };
+
+    match result {
+        Some(Ok(record)) => record,
+        Some(Err(payload)) => Record::panicked(day, part, timer.elapsed(), panic_message(payload)),
+        None => Record::timeout(day, part, timer.elapsed()),
+    }
+}
+
+/// Runs the selected parts of a day on its real input.
+pub fn run_day(solution: &Solution, options: &Options) -> Vec<Record> {
+    let parts = solution
+        .parts()
+        .into_iter()
+        .filter(|(part, _)| options.runs_part(*part));
+
+    let input: Arc<str> = match crate::try_read_file("inputs", solution.year, solution.day) {
+        Ok(input) => input.into(),
+        Err(e) => {
+            return parts
+                .map(|(part, _)| Record::input_error(solution.day, part, &e))
+                .collect()
+        }
+    };
+
+    parts
+        .map(|(part, solver)| run_part_isolated(solution.day, part, solver, input.clone(), options))
+        .collect()
+}
+
+/// Runs the days on up to `jobs` threads. Parts of one day always run on the same thread, one
+/// after another. `report` is called in the order of `solutions`, as soon as a day is done.
+pub fn run_days<'a>(
+    solutions: &[&'a Solution],
+    options: &Options,
+    jobs: usize,
+    report: impl FnMut(&'a Solution, Vec<Record>),
+) {
+    run_days_with(solutions, jobs, |s| run_day(s, options), report)
+}
+
+fn run_days_with<'a>(
+    solutions: &[&'a Solution],
+    jobs: usize,
+    run: impl Fn(&Solution) -> Vec<Record> + Sync,
+    mut report: impl FnMut(&'a Solution, Vec<Record>),
+) {
+    if jobs <= 1 {
+        for solution in solutions {
+            report(solution, run(solution));
+        }
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(solutions.len()) {
+            let sender = sender.clone();
+            let (next, run) = (&next, &run);
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::SeqCst);
+                match solutions.get(index) {
+                    Some(solution) => sender.send((index, run(solution))).unwrap(),
+                    None => break,
+                }
+            });
+        }
+        drop(sender);
+
+        // buffer days that finished early until all days before them are reported.
+        let mut pending = BTreeMap::new();
+        let mut reported = 0;
+        for (index, records) in receiver {
+            pending.insert(index, records);
+            while let Some(records) = pending.remove(&reported) {
+                report(solutions[reported], records);
+                reported += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::Status;
+
+    #[test]
+    fn test_options_part() {
+        let options = |args: &[&str]| {
+            let args = args.iter().map(|a| a.into()).collect();
+            Options::from_args(&mut pico_args::Arguments::from_vec(args))
+        };
+        assert_eq!(options(&["--part", "2"]).unwrap().part, Some(2));
+        assert_eq!(options(&[]).unwrap().part, None);
+        assert!(options(&["--part", "3"]).is_err());
+        assert!(options(&["-p", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_micros);
+        let stats = Stats::from_samples(&samples);
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        // population standard deviation of 1, 2, 3, 4 is sqrt(1.25)
+        assert_eq!(stats.stddev.as_nanos(), 1118);
+
+        let stats = Stats::from_samples(&[Duration::from_millis(7)]);
+        assert_eq!(stats.median, Duration::from_millis(7));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_run_days_reports_in_order() {
+        let solutions = (1..=8)
+            .map(|day| Solution {
+                year: 2022,
+                day,
+                part_one: |_| None,
+                part_two: |_| None,
+            })
+            .collect::<Vec<_>>();
+        let solutions = solutions.iter().collect::<Vec<_>>();
+        // earlier days take longer, so they finish after later ones.
+        let run = |solution: &Solution| {
+            thread::sleep(Duration::from_millis(5 * (8 - solution.day as u64)));
+            vec![Record::new(solution.day, 1, Some(1), Duration::ZERO)]
+        };
+
+        let mut days = vec![];
+        run_days_with(&solutions, 4, run, |solution, records| {
+            assert_eq!(records[0].day, solution.day);
+            days.push(solution.day);
+        });
+        assert_eq!(days, (1..=8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_run_part_isolated() {
+        let options = Options {
+            format: Format::Text,
+            part: None,
+            bench: None,
+            timeout: Some(Duration::from_millis(50)),
+        };
+
+        let record = run_part_isolated(1, 1, |i| Some(i.len().to_string()), "abc".into(), &options);
+        assert_eq!(record.status, Status::Solved);
+        assert_eq!(record.answer.as_deref(), Some("3"));
+
+        let record = run_part_isolated(1, 2, |_| panic!("boom"), "".into(), &options);
+        assert_eq!(record.status, Status::Panicked);
+        assert_eq!(record.error.as_deref(), Some("boom"));
+
+        let record = run_part_isolated(
+            2,
+            1,
+            |_| {
+                thread::sleep(Duration::from_secs(1));
+                None
+            },
+            "".into(),
+            &options,
+        );
+        assert_eq!(record.status, Status::Timeout);
+    }
+}
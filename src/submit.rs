@@ -0,0 +1,247 @@
+/*
+ * This file contains template code.
+ * Outcomes of submitted answers, and the history of all attempts in `src/<year>/answers/attempts.json`,
+ * which keeps `cargo submit` from sending answers that are already known to be wrong.
+ */
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::input;
+
+pub const HISTORY_FILE: &str = "attempts.json";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    /// The answer was not checked, `wait` is the remaining time as the server put it.
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part was already solved, or part one is not solved yet when submitting part two.
+    WrongLevel,
+    /// The server responded with something that is not understood.
+    Unknown,
+}
+
+impl Outcome {
+    /// Whether the server actually checked the answer.
+    pub fn is_checked(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Correct | Outcome::Wrong | Outcome::TooHigh | Outcome::TooLow
+        )
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer!"),
+            Outcome::Wrong => write!(f, "That's not the right answer."),
+            Outcome::TooHigh => write!(f, "That's not the right answer, it is too high."),
+            Outcome::TooLow => write!(f, "That's not the right answer, it is too low."),
+            Outcome::RateLimited { wait: Some(wait) } => {
+                write!(
+                    f,
+                    "Submitted too recently, wait {} before trying again.",
+                    wait
+                )
+            }
+            Outcome::RateLimited { wait: None } => {
+                write!(f, "Submitted too recently, wait before trying again.")
+            }
+            Outcome::WrongLevel => write!(
+                f,
+                "Not the right level, is the part already solved or the previous one not yet?"
+            ),
+            Outcome::Unknown => write!(f, "Could not understand the response of the server."),
+        }
+    }
+}
+
+/// Reads the outcome from the page the server responds to an answer with.
+pub fn parse_response(body: &str) -> Outcome {
+    // only the sentences matter, not the markup around them.
+    let text = body.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if text.contains("your answer is too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Wrong
+        }
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| wait.to_string());
+        Outcome::RateLimited { wait }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Unix timestamp in seconds.
+    pub submitted_at: u64,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn path(year: u16) -> PathBuf {
+        crate::year_dir("answers", year).join(HISTORY_FILE)
+    }
+
+    /// A missing history is the same as an empty one.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).map_err(|e| e.to_string()),
+            Err(_) => Ok(History::default()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::create_dir_all(path.parent().unwrap()).map_err(|e| e.to_string())?;
+        let contents = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        input::write_atomic(path, &(contents + "\n")).map_err(|e| e.to_string())
+    }
+
+    pub fn attempts(&self, year: u16, day: u8, part: u8) -> impl Iterator<Item = &Attempt> {
+        self.attempts
+            .iter()
+            .filter(move |a| a.year == year && a.day == day && a.part == part)
+    }
+
+    pub fn record(&mut self, year: u16, day: u8, part: u8, answer: &str, outcome: Outcome) {
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        self.attempts.push(Attempt {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            outcome,
+            submitted_at,
+        });
+    }
+
+    /// Refuses answers that earlier attempts already rule out: the part is solved, the same
+    /// answer was wrong, or a number is not below a too high or above a too low answer.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<(), String> {
+        let number = answer.parse::<i128>().ok();
+        for attempt in self.attempts(year, day, part) {
+            let earlier = attempt.answer.parse::<i128>().ok();
+            match (&attempt.outcome, number, earlier) {
+                (Outcome::Correct, _, _) => {
+                    return Err(format!("already solved with \"{}\"", attempt.answer))
+                }
+                (outcome, _, _) if outcome.is_checked() && attempt.answer == answer => {
+                    return Err(format!("\"{}\" was already submitted: {}", answer, outcome))
+                }
+                (Outcome::TooHigh, Some(number), Some(bound)) if number >= bound => {
+                    return Err(format!("{} is too high, \"{}\" already was", number, bound))
+                }
+                (Outcome::TooLow, Some(number), Some(bound)) if number <= bound => {
+                    return Err(format!("{} is too low, \"{}\" already was", number, bound))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        let page = |text: &str| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer!  You are one gold star closer."
+            )),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too high.  Please wait one minute."
+            )),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer.  If you're stuck, make sure you're using the full\ninput data."
+            )),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 34s left to wait."
+            )),
+            Outcome::RateLimited {
+                wait: Some("34s".to_string())
+            }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Outcome::WrongLevel
+        );
+        assert_eq!(parse_response("<html></html>"), Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut history = History::default();
+        history.record(2022, 1, 1, "50", Outcome::TooHigh);
+        history.record(2022, 1, 1, "10", Outcome::TooLow);
+        history.record(2022, 1, 1, "30", Outcome::Wrong);
+        history.record(2022, 1, 1, "20", Outcome::RateLimited { wait: None });
+
+        assert!(history.check(2022, 1, 1, "50").is_err());
+        assert!(history.check(2022, 1, 1, "51").is_err());
+        assert!(history.check(2022, 1, 1, "10").is_err());
+        assert!(history.check(2022, 1, 1, "30").is_err());
+        assert!(history.check(2022, 1, 1, "20").is_ok());
+        assert!(history.check(2022, 1, 1, "49").is_ok());
+        assert!(history.check(2022, 1, 1, "abc").is_ok());
+        assert!(history.check(2022, 1, 2, "50").is_ok());
+
+        history.record(2022, 1, 1, "40", Outcome::Correct);
+        assert_eq!(
+            history.check(2022, 1, 1, "41"),
+            Err("already solved with \"40\"".to_string())
+        );
+
+        let json = serde_json::to_string(&history).unwrap();
+        assert_eq!(serde_json::from_str::<History>(&json).unwrap(), history);
+    }
+}
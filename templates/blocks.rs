@@ -0,0 +1,31 @@
+/// Blocks are separated by an empty line.
+fn parse(input: &str) -> Vec<Vec<&str>> {
+    input
+        .split("\n\n")
+        .map(|block| block.lines().collect())
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Option<{{answer}}> {
+    let _blocks = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{answer}}> {
+    let _blocks = parse(input);
+    None
+}
+
+#[allow(dead_code)]
+fn main() {
+    let input = &advent_of_code::try_read_file("inputs", {{year}}, {{day}});
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+{{tests}}
+}
@@ -0,0 +1,52 @@
+use advent_of_code::helpers::{Point, PointGrid};
+
+/// One point per character, `(0,0)` is the top left corner.
+fn parse(input: &str) -> PointGrid<char> {
+    let mut grid = PointGrid::default();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.chars().enumerate() {
+            grid.insert(
+                Point {
+                    x: x as isize,
+                    y: y as isize,
+                },
+                c,
+            );
+        }
+    }
+    grid
+}
+
+pub fn part_one(input: &str) -> Option<{{answer}}> {
+    let _grid = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{answer}}> {
+    let _grid = parse(input);
+    None
+}
+
+#[allow(dead_code)]
+fn main() {
+    let input = &advent_of_code::try_read_file("inputs", {{year}}, {{day}});
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid = parse("ab\ncd\n");
+        assert_eq!(grid.get(&Point { x: 1, y: 0 }), Some(&'b'));
+        assert_eq!(
+            grid.dimensions(),
+            (Point { x: 0, y: 0 }, Point { x: 1, y: 1 })
+        );
+    }
+
+{{tests}}
+}
@@ -0,0 +1,27 @@
+fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part_one(input: &str) -> Option<{{answer}}> {
+    let _lines = parse(input);
+    None
+}
+
+pub fn part_two(input: &str) -> Option<{{answer}}> {
+    let _lines = parse(input);
+    None
+}
+
+#[allow(dead_code)]
+fn main() {
+    let input = &advent_of_code::try_read_file("inputs", {{year}}, {{day}});
+    advent_of_code::solve!(1, part_one, input);
+    advent_of_code::solve!(2, part_two, input);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+{{tests}}
+}
@@ -0,0 +1,36 @@
+advent_of_code::params! {
+    /// Constants that differ between the example and the real input, override them with
+    /// `--param key=value`.
+    pub struct Params {
+        size: usize = { real: 100, example: 10 },
+    }
+}
+
+fn parse(input: &str) -> Vec<&str> {
+    input.lines().collect()
+}
+
+pub fn part_one(input: &str, _params: &Params) -> Option<{{answer}}> {
+    let _lines = parse(input);
+    None
+}
+
+pub fn part_two(input: &str, _params: &Params) -> Option<{{answer}}> {
+    let _lines = parse(input);
+    None
+}
+
+#[allow(dead_code)]
+fn main() {
+    let input = &advent_of_code::try_read_file("inputs", {{year}}, {{day}});
+    advent_of_code::solve!(1, part_one, input, Params);
+    advent_of_code::solve!(2, part_two, input, Params);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::params::Params as _;
+
+{{tests}}
+}
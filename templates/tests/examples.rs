@@ -0,0 +1,5 @@
+    /// Expected answers are in `src/{{year}}/examples/{{day_padded}}_answers.toml`.
+    #[test]
+    fn test_examples() {
+        advent_of_code::answers::check_examples({{year}}, {{day}}, part_one, part_two);
+    }
@@ -0,0 +1,11 @@
+    /// Expected answers are in `src/{{year}}/examples/{{day_padded}}_answers.toml`.
+    #[test]
+    fn test_examples() {
+        let params = Params::example();
+        advent_of_code::answers::check_examples(
+            {{year}},
+            {{day}},
+            |input| part_one(input, &params),
+            |input| part_two(input, &params),
+        );
+    }
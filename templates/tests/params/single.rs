@@ -0,0 +1,11 @@
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file("examples", {{year}}, {{day}});
+        assert_eq!(part_one(&input, &Params::example()), None);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file("examples", {{year}}, {{day}});
+        assert_eq!(part_two(&input, &Params::example()), None);
+    }